@@ -0,0 +1,86 @@
+//! An example plugin with another solver for day1 part1 of 2023. Build it with
+//! `cargo build --release` and load it with
+//! `--plugin plugins/example/target/release/libaoc_plugin_example.so`.
+
+use std::{
+    ffi::{c_char, CString},
+    panic,
+};
+
+// The types of the plugin interface, they must match `src/plugin.rs`.
+
+const ABI_VERSION: u32 = 1;
+
+#[repr(C)]
+pub struct Table {
+    abi_version: u32,
+    solvers: *const PluginSolver,
+    count: usize,
+    free: unsafe extern "C" fn(answer: *mut c_char),
+}
+
+#[repr(C)]
+pub struct PluginSolver {
+    year: u32,
+    day: u32,
+    part: u32,
+    name: *const c_char,
+    solve: unsafe extern "C" fn(input: *const u8, len: usize, answer: *mut *mut c_char) -> i32,
+}
+
+// The table only points to static data, so it can be shared.
+struct Shared<T>(T);
+unsafe impl<T> Sync for Shared<T> {}
+
+static SOLVERS: Shared<[PluginSolver; 1]> = Shared([PluginSolver {
+    year: 2023,
+    day: 1,
+    part: 1,
+    name: c"example".as_ptr(),
+    solve: day1_part1,
+}]);
+
+static TABLE: Shared<Table> = Shared(Table {
+    abi_version: ABI_VERSION,
+    solvers: SOLVERS.0.as_ptr(),
+    count: SOLVERS.0.len(),
+    free,
+});
+
+#[no_mangle]
+pub extern "C" fn aoc_plugin() -> *const Table {
+    &TABLE.0
+}
+
+unsafe extern "C" fn free(answer: *mut c_char) {
+    drop(CString::from_raw(answer));
+}
+
+unsafe extern "C" fn day1_part1(input: *const u8, len: usize, answer: *mut *mut c_char) -> i32 {
+    let input = std::slice::from_raw_parts(input, len);
+
+    // Panics must not unwind into the host.
+    let (status, text) = match panic::catch_unwind(|| calibration(input)) {
+        Ok(Ok(sum)) => (0, sum.to_string()),
+        Ok(Err(err)) => (1, err),
+        Err(_) => (1, "panicked".to_string()),
+    };
+
+    *answer = CString::new(text).unwrap_or_default().into_raw();
+    status
+}
+
+fn calibration(input: &[u8]) -> Result<u32, String> {
+    input
+        .split(|&b| b == b'\n')
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut digits = line
+                .iter()
+                .filter(|b| b.is_ascii_digit())
+                .map(|b| (b - b'0') as u32);
+            let first = digits.next().ok_or("a line without digits")?;
+            Ok(first * 10 + digits.next_back().unwrap_or(first))
+        })
+        .sum()
+}
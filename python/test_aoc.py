@@ -0,0 +1,72 @@
+"""Tests of the Python bindings on the puzzle examples.
+
+Build the module and run the tests from the root of the repository:
+
+    cargo build --release --features python
+    cp target/release/libaoc.so python/aoc.so
+    python3 -m unittest discover python
+"""
+
+import pathlib
+import unittest
+
+import aoc
+from aoc import day5, day12, day19
+
+EXAMPLES = pathlib.Path(__file__).parent.parent / "examples" / "2023"
+
+
+def example(day):
+    return (EXAMPLES / f"day{day}" / "sample.txt").read_text()
+
+
+class TestSolve(unittest.TestCase):
+    def test_solve(self):
+        self.assertEqual(35, aoc.solve(5, 1, example(5)))
+        self.assertEqual(46, aoc.solve(5, 2, example(5), year=2023))
+
+    def test_errors(self):
+        with self.assertRaises(ValueError):
+            aoc.solve(5, 1, "not an almanac")
+        with self.assertRaises(ValueError):
+            aoc.solve(26, 1, example(5))
+
+
+class TestDay5(unittest.TestCase):
+    def test_almanac(self):
+        almanac = day5.parse(example(5))
+        self.assertEqual([79, 14, 55, 13], almanac.seeds)
+        self.assertEqual([(79, 93), (55, 68)], almanac.seed_ranges)
+        self.assertEqual(82, almanac.location(79))
+
+        locations = almanac.resolve_ranges(almanac.seed_ranges)
+        self.assertEqual(46, locations[0][0])
+        self.assertEqual([], almanac.resolve_ranges([(10, 10)]))
+
+
+class TestDay12(unittest.TestCase):
+    def test_parse(self):
+        rows = day12.parse(example(12))
+        self.assertEqual(("???.###", [1, 1, 3]), rows[0])
+
+    def test_count_alternatives(self):
+        self.assertEqual(10, day12.count_alternatives("?###????????", [3, 2, 1]))
+        with self.assertRaises(ValueError):
+            day12.count_alternatives("?x?", [1])
+
+
+class TestDay19(unittest.TestCase):
+    def test_accepted_ranges(self):
+        ranges = day19.accepted_ranges(example(19))
+        combinations = 0
+        for r in ranges:
+            product = 1
+            for category in "xmas":
+                start, end = r[category]
+                product *= end - start + 1
+            combinations += product
+        self.assertEqual(167409079868000, combinations)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,128 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// Wraps the system allocator to count the allocations made on each thread.
+pub struct Counting;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of allocations.
+    pub count: u64,
+    /// The total number of bytes allocated.
+    pub bytes: u64,
+    /// The most bytes that were allocated at the same time.
+    pub peak: u64,
+}
+
+thread_local! {
+    // Only const initialized cells without destructors can be used from the
+    // allocator, anything else could allocate itself.
+    static COUNT: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+    static LIVE: Cell<i64> = const { Cell::new(0) };
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        allocated(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        allocated(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        freed(layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        freed(layout.size());
+        allocated(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+fn allocated(size: usize) {
+    // The counters may already be gone while the thread is shutting down.
+    let _ = COUNT.try_with(|c| c.set(c.get() + 1));
+    let _ = BYTES.try_with(|b| b.set(b.get() + size as u64));
+    let _ = LIVE.try_with(|live| {
+        live.set(live.get() + size as i64);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+    });
+}
+
+fn freed(size: usize) {
+    let _ = LIVE.try_with(|live| live.set(live.get() - size as i64));
+}
+
+/// Runs `f` and counts the allocations it makes on this thread. The peak is
+/// relative to what was already allocated when `f` started.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Stats) {
+    let count = COUNT.get();
+    let bytes = BYTES.get();
+    let live = LIVE.get();
+    let peak = PEAK.replace(live);
+
+    let result = f();
+
+    let stats = Stats {
+        count: COUNT.get() - count,
+        bytes: BYTES.get() - bytes,
+        peak: (PEAK.get() - live).max(0) as u64,
+    };
+    PEAK.set(peak.max(PEAK.get()));
+
+    (result, stats)
+}
+
+/// Formats a number of bytes with a binary unit, e.g. "512B" or "1.5MiB".
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1}{}", UNITS[unit])
+}
+
+#[test]
+fn test_measure() {
+    let (v, stats) = measure(|| vec![0u8; 4096]);
+
+    assert_eq!(4096, v.len());
+    assert_eq!(1, stats.count);
+    assert_eq!(4096, stats.bytes);
+    assert_eq!(4096, stats.peak);
+
+    let (_, stats) = measure(|| {
+        for _ in 0..10 {
+            drop(vec![0u8; 1000]);
+        }
+    });
+
+    assert_eq!(10, stats.count);
+    assert_eq!(10_000, stats.bytes);
+    assert_eq!(1000, stats.peak);
+}
+
+#[test]
+fn test_format_bytes() {
+    assert_eq!("512B", format_bytes(512));
+    assert_eq!("1.5KiB", format_bytes(1536));
+    assert_eq!("3.0MiB", format_bytes(3 << 20));
+}
@@ -0,0 +1,215 @@
+use std::{
+    io::{BufRead, Write},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    time::Duration,
+};
+
+use crate::simulation::Simulation;
+
+/// Moves the cursor to the top left and clears the screen.
+const CLEAR: &str = "\x1b[H\x1b[2J";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+
+const HELP: &str = "p: pause/resume, s: step, q: quit (then Enter)";
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Control {
+    Pause,
+    Step,
+    Quit,
+}
+
+impl Control {
+    /// Reads a control from a line typed on stdin. An empty line pauses or
+    /// resumes as well.
+    fn parse(line: &str) -> Option<Control> {
+        match line.trim() {
+            "" | "p" | "pause" => Some(Control::Pause),
+            "s" | "step" | "n" => Some(Control::Step),
+            "q" | "quit" => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Reads controls from stdin on a thread of its own. The terminal only passes
+/// on a line once Enter is pressed.
+pub fn stdin_controls() -> Receiver<Control> {
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if let Some(control) = Control::parse(&line) {
+                if sender.send(control).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    receiver
+}
+
+/// Plays the simulation as frames on a terminal, `fps` frames per second,
+/// until it finishes, `max_steps` steps have been taken, or it is quit.
+/// Returns the number of steps taken.
+pub fn play(
+    sim: &mut dyn Simulation,
+    fps: f64,
+    max_steps: usize,
+    paused: bool,
+    controls: &Receiver<Control>,
+    out: &mut dyn Write,
+) -> Result<usize> {
+    let frame = Duration::from_secs_f64(1.0 / fps.max(0.1));
+    let mut paused = paused;
+    let mut steps = 0;
+
+    write!(out, "{HIDE_CURSOR}").map_err(|err| err.to_string())?;
+
+    let result = loop {
+        let done = steps == max_steps || sim.finished();
+        let state = match (done, paused) {
+            (true, _) => "done",
+            (false, true) => "paused",
+            (false, false) => "playing",
+        };
+        write!(
+            out,
+            "{CLEAR}{}\nstep {steps}, {state}\n{HELP}\n",
+            sim.render()
+        )
+        .and_then(|_| out.flush())
+        .map_err(|err| err.to_string())?;
+
+        if done {
+            break Ok(steps);
+        }
+
+        // Wait for the next frame, or for a control while paused. Once stdin
+        // is closed there are no more controls to wait for.
+        let control = if paused {
+            controls.recv().ok()
+        } else {
+            match controls.recv_timeout(frame) {
+                Ok(control) => Some(control),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => {
+                    std::thread::sleep(frame);
+                    None
+                }
+            }
+        };
+
+        match control {
+            Some(Control::Quit) => break Ok(steps),
+            Some(Control::Pause) => {
+                paused = !paused;
+                continue;
+            }
+            Some(Control::Step) => paused = true,
+            None if paused => paused = false,
+            None => {}
+        }
+
+        if let Err(err) = sim.step() {
+            break Err(err);
+        }
+        steps += 1;
+    };
+
+    write!(out, "{SHOW_CURSOR}").map_err(|err| err.to_string())?;
+
+    result
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[cfg(test)]
+use crate::simulation::{Snapshot, State};
+
+/// Counts up to a limit.
+#[cfg(test)]
+#[derive(Clone)]
+struct Counter(usize, usize);
+
+#[cfg(test)]
+impl Simulation for Counter {
+    fn step(&mut self) -> Result<()> {
+        self.0 += 1;
+        Ok(())
+    }
+
+    fn finished(&self) -> bool {
+        self.0 == self.1
+    }
+
+    fn state(&self) -> State {
+        State::of(&self.0)
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new(self)
+    }
+
+    fn restore(&mut self, snapshot: &Snapshot) {
+        *self = snapshot.get();
+    }
+
+    fn render(&self) -> String {
+        format!("count {}", self.0)
+    }
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(Some(Control::Pause), Control::parse(""));
+    assert_eq!(Some(Control::Step), Control::parse(" s "));
+    assert_eq!(Some(Control::Quit), Control::parse("quit"));
+    assert_eq!(None, Control::parse("x"));
+}
+
+#[test]
+fn test_play() {
+    let (sender, receiver) = mpsc::channel();
+    drop(sender);
+
+    let mut out = Vec::new();
+    let steps = play(&mut Counter(0, 3), 1000.0, 10, false, &receiver, &mut out);
+    assert_eq!(Ok(3), steps);
+
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(4, out.matches(CLEAR).count());
+    assert!(out.contains("count 3\nstep 3, done\n"));
+    assert!(out.starts_with(HIDE_CURSOR) && out.ends_with(SHOW_CURSOR));
+
+    let mut out = Vec::new();
+    let steps = play(&mut Counter(0, 3), 1000.0, 2, false, &receiver, &mut out);
+    assert_eq!(Ok(2), steps);
+}
+
+#[test]
+fn test_controls() {
+    // Starting paused, each step control takes a single step.
+    let (sender, receiver) = mpsc::channel();
+    for control in [Control::Step, Control::Step, Control::Quit] {
+        sender.send(control).unwrap();
+    }
+
+    let mut out = Vec::new();
+    let steps = play(&mut Counter(0, 10), 1.0, 10, true, &receiver, &mut out);
+    assert_eq!(Ok(2), steps);
+
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("count 0\nstep 0, paused\n"));
+    assert!(out.contains("count 2\nstep 2, paused\n"));
+}
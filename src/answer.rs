@@ -0,0 +1,211 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use num::{BigInt, ToPrimitive};
+
+use crate::util;
+
+/// The answer of a part. Answers compare by value, so an `Int` equals a `Big`
+/// or a `Text` holding the same number, e.g. "0042" equals 42.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Int(i128),
+    /// A number too large for an `Int`.
+    Big(BigInt),
+    Text(String),
+}
+
+impl Answer {
+    /// Reads an answer from text, e.g. a known answer or the output of a
+    /// plugin. Numbers become an `Int` or `Big`, anything else stays text.
+    pub fn parse(s: &str) -> Answer {
+        match parse_number(s) {
+            Some(n) => Answer::from(n),
+            None => Answer::Text(s.trim().to_string()),
+        }
+    }
+
+    /// Renders the answer as a JSON value, numbers stay numbers.
+    pub fn to_json(&self) -> String {
+        match self {
+            Answer::Int(n) => n.to_string(),
+            Answer::Big(n) => n.to_string(),
+            Answer::Text(s) => util::json_string(s),
+        }
+    }
+
+    fn number(&self) -> Option<BigInt> {
+        match self {
+            Answer::Int(n) => Some(BigInt::from(*n)),
+            Answer::Big(n) => Some(n.clone()),
+            Answer::Text(s) => parse_number(s),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        match (self, other) {
+            (Answer::Int(a), Answer::Int(b)) => a == b,
+            (Answer::Text(a), Answer::Text(b)) if a.trim() == b.trim() => true,
+            _ => match (self.number(), other.number()) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    /// Hashes the value, so answers that are equal hash the same.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.number() {
+            Some(n) => n.hash(state),
+            None => self.to_string().trim().hash(state),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Big(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Int(n.into())
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Answer {
+        Answer::Int(n as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Answer {
+        Answer::Int(n as i128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        Answer::from(BigInt::from(n))
+    }
+}
+
+impl From<BigInt> for Answer {
+    /// Numbers that fit stay an `Int`.
+    fn from(n: BigInt) -> Answer {
+        match n.to_i128() {
+            Some(n) => Answer::Int(n),
+            None => Answer::Big(n),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// Why a part has no answer. Inputs that cannot be parsed are told apart, so
+/// the command line can exit with a code of its own for them.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Unsolved {
+    /// The input is not in the format of the day.
+    Parse(String),
+    /// The input was understood, but the solver found no answer in it.
+    Failed(String),
+}
+
+/// What a part returns. Errors that are not marked as `Parse` become `Failed`
+/// through `?`.
+pub type Solution = std::result::Result<Answer, Unsolved>;
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Unsolved::Parse(err) | Unsolved::Failed(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<String> for Unsolved {
+    fn from(err: String) -> Unsolved {
+        Unsolved::Failed(err)
+    }
+}
+
+impl From<&str> for Unsolved {
+    fn from(err: &str) -> Unsolved {
+        Unsolved::Failed(err.to_string())
+    }
+}
+
+/// Reads an optionally signed decimal number, ignoring surrounding spaces.
+fn parse_number(s: &str) -> Option<BigInt> {
+    let s = s.trim();
+    let digits = s.strip_prefix(['-', '+']).unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
+#[test]
+fn test_eq() {
+    assert_eq!(Answer::Int(42), Answer::from("0042"));
+    assert_eq!(Answer::from(" 42 "), Answer::from("+42"));
+    assert_eq!(Answer::Big(BigInt::from(42)), Answer::Int(42));
+    assert_ne!(Answer::Int(42), Answer::Int(-42));
+    assert_ne!(Answer::Int(42), Answer::from("42a"));
+    assert_eq!(Answer::from("abc"), Answer::from("abc"));
+    assert_ne!(Answer::from("abc"), Answer::from("abd"));
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!("Int(-7)", format!("{:?}", Answer::parse("-7")));
+    assert_eq!("Text(\"A7\")", format!("{:?}", Answer::parse(" A7 ")));
+
+    let big = "1".repeat(50);
+    assert!(matches!(Answer::parse(&big), Answer::Big(_)));
+    assert_eq!(big, Answer::parse(&big).to_string());
+}
+
+#[test]
+fn test_to_json() {
+    assert_eq!("142", Answer::from(142u64).to_json());
+    assert_eq!(
+        "340282366920938463463374607431768211455",
+        Answer::from(u128::MAX).to_json()
+    );
+    assert_eq!(r#""a\"b""#, Answer::from("a\"b").to_json());
+}
@@ -0,0 +1,314 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    answer::Answer,
+    detect, fixtures, registry,
+    runner::{self, Outcome},
+    util,
+};
+
+/// A run is an outlier if it took this many times longer than the median of
+/// the other runs of the same part.
+pub const SLOW_FACTOR: f64 = 10.0;
+
+/// Runs faster than this are never outliers, the timings are mostly noise.
+const MIN_SLOW: Duration = Duration::from_millis(1);
+
+struct Run {
+    file: String,
+    day: u32,
+    part: u32,
+    outcome: Outcome,
+    expected: Option<Answer>,
+}
+
+/// Runs every `dayN*.txt` input in `dir` through both parts of its day, e.g.
+/// `day8.txt` and `day8-alice.txt` both go to day8. Answers can be checked by
+/// putting them in a `.expected` file next to the input, in the same format as
+/// the examples.
+///
+/// Returns a table of answers and timings per day, followed by the runs that
+/// stand out: errors, timeouts, wrong answers, slow runs and inputs that look
+/// like another day.
+pub fn batch(dir: &Path, year: u32, timeout: Duration) -> Result<String> {
+    let inputs = find_inputs(dir)?;
+    if inputs.is_empty() {
+        return Err(format!("no dayN*.txt inputs in {}", dir.display()));
+    }
+
+    let mut runs = Vec::new();
+    let mut wrong_day = Vec::new();
+
+    for (day, path) in inputs.iter() {
+        let file = path.file_name().unwrap().to_string_lossy().to_string();
+        let input = read(path)?;
+        let expected = fixtures::read_expected(&path.with_extension("expected"))?;
+
+        if let Some(warning) = detect::check(year, *day, &input) {
+            wrong_day.push(format!("{file}: {warning}"));
+        }
+
+        for part in [1, 2]
+            .into_iter()
+            .filter(|&part| registry::has_puzzle(*day, part))
+        {
+            let Some(solver) = registry::find(year, *day, part) else {
+                continue;
+            };
+
+            runs.push(Run {
+                file: file.clone(),
+                day: *day,
+                part,
+                outcome: runner::run_with_timeout(&solver, &input, timeout),
+                expected: expected.get(&part).cloned(),
+            });
+        }
+    }
+
+    let mut out = render_matrix(&runs);
+
+    let outliers = [
+        ("errors", errors(&runs)),
+        ("wrong answers", wrong_answers(&runs)),
+        ("slow", slow(&runs, SLOW_FACTOR)),
+        ("wrong day", wrong_day),
+    ];
+
+    for (title, lines) in outliers.iter().filter(|(_, lines)| !lines.is_empty()) {
+        out.push_str(&format!("\n{title}:\n"));
+        for line in lines {
+            out.push_str(&format!("  {line}\n"));
+        }
+    }
+
+    Ok(out)
+}
+
+/// Returns the `dayN*.txt` files in the directory with their day, sorted by day
+/// and name.
+fn find_inputs(dir: &Path) -> Result<Vec<(u32, PathBuf)>> {
+    let mut inputs = fs::read_dir(dir)
+        .map_err(|err| format!("{}: {err}", dir.display()))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|p| Some((input_day(&p.file_stem()?.to_string_lossy())?, p)))
+        .collect::<Vec<_>>();
+
+    inputs.sort();
+
+    Ok(inputs)
+}
+
+/// Returns the day of an input named like "day8" or "day8-alice".
+fn input_day(name: &str) -> Option<u32> {
+    let rest = name.strip_prefix("day")?;
+    let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+
+    rest[..digits].parse().ok()
+}
+
+fn render_matrix(runs: &[Run]) -> String {
+    let mut days = BTreeMap::<u32, BTreeMap<&str, Vec<&Run>>>::new();
+    for run in runs {
+        days.entry(run.day)
+            .or_default()
+            .entry(&run.file)
+            .or_default()
+            .push(run);
+    }
+
+    let mut out = String::new();
+
+    for (day, files) in days.iter() {
+        let mut header = vec!["file".to_string()];
+        for part in [1, 2] {
+            header.push(format!("part{part}"));
+            header.push("time".to_string());
+        }
+
+        let rows = files
+            .iter()
+            .map(|(file, runs)| {
+                let mut row = vec![file.to_string()];
+                for part in [1, 2] {
+                    match runs.iter().find(|r| r.part == part) {
+                        Some(run) => {
+                            row.push(match &run.outcome.answer {
+                                Ok(answer) => answer.to_string(),
+                                Err(_) => "error".to_string(),
+                            });
+                            row.push(runner::format_duration(run.outcome.elapsed));
+                        }
+                        None => row.extend(["-".to_string(), "-".to_string()]),
+                    }
+                }
+                row
+            })
+            .collect::<Vec<_>>();
+
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&format!("day{day}:\n"));
+        out.push_str(&util::text_table(&header, &rows));
+    }
+
+    out
+}
+
+fn errors(runs: &[Run]) -> Vec<String> {
+    runs.iter()
+        .filter_map(|r| {
+            let err = r.outcome.answer.as_ref().err()?;
+            Some(format!("{} part{}: {err}", r.file, r.part))
+        })
+        .collect()
+}
+
+fn wrong_answers(runs: &[Run]) -> Vec<String> {
+    runs.iter()
+        .filter_map(|r| {
+            let answer = r.outcome.answer.as_ref().ok()?;
+            let expected = r.expected.as_ref()?;
+
+            (answer != expected).then(|| {
+                format!(
+                    "{} part{}: expected {expected}, got {answer}",
+                    r.file, r.part
+                )
+            })
+        })
+        .collect()
+}
+
+/// Finds the successful runs that took much longer than the other runs of the
+/// same day and part.
+fn slow(runs: &[Run], factor: f64) -> Vec<String> {
+    let mut slow = Vec::new();
+
+    for (i, run) in runs.iter().enumerate() {
+        if run.outcome.answer.is_err() || run.outcome.elapsed < MIN_SLOW {
+            continue;
+        }
+
+        let mut others = runs
+            .iter()
+            .enumerate()
+            .filter(|&(j, r)| j != i && r.day == run.day && r.part == run.part)
+            .filter(|(_, r)| r.outcome.answer.is_ok())
+            .map(|(_, r)| r.outcome.elapsed)
+            .collect::<Vec<_>>();
+
+        if others.is_empty() {
+            continue;
+        }
+
+        others.sort();
+        let median = others[others.len() / 2];
+        let ratio = run.outcome.elapsed.as_secs_f64() / median.as_secs_f64().max(1e-9);
+
+        if ratio >= factor {
+            slow.push(format!(
+                "{} part{}: {}, {ratio:.0}x the median of {}",
+                run.file,
+                run.part,
+                runner::format_duration(run.outcome.elapsed),
+                runner::format_duration(median)
+            ));
+        }
+    }
+
+    slow
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[test]
+fn test_input_day() {
+    assert_eq!(Some(8), input_day("day8"));
+    assert_eq!(Some(8), input_day("day8-alice"));
+    assert_eq!(Some(11), input_day("day11_bob"));
+    assert_eq!(None, input_day("day"));
+    assert_eq!(None, input_day("notes"));
+}
+
+#[test]
+fn test_slow() {
+    let run = |file: &str, millis: u64| Run {
+        file: file.to_string(),
+        day: 8,
+        part: 2,
+        outcome: Outcome {
+            answer: Ok(1.into()),
+            elapsed: Duration::from_millis(millis),
+            allocs: Default::default(),
+        },
+        expected: None,
+    };
+
+    let runs = vec![run("a", 2), run("b", 3), run("c", 40), run("d", 2)];
+    assert_eq!(
+        vec!["c part2: 40.0ms, 20x the median of 2.0ms".to_string()],
+        slow(&runs, SLOW_FACTOR)
+    );
+
+    // A single run has nothing to compare to.
+    assert!(slow(&runs[2..3], SLOW_FACTOR).is_empty());
+}
+
+#[test]
+fn test_batch() {
+    let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let sample = |day: u32, name: &str| {
+        fs::read_to_string(format!(
+            "{}/2023/day{day}/{name}.txt",
+            fixtures::EXAMPLES_DIR
+        ))
+        .unwrap()
+    };
+
+    fs::write(dir.join("day1-a.txt"), sample(1, "sample")).unwrap();
+    fs::write(dir.join("day1-a.expected"), "part1 = 142\npart2 = 143\n").unwrap();
+    fs::write(dir.join("day1-b.txt"), sample(1, "sample2")).unwrap();
+    fs::write(dir.join("day6.txt"), sample(2, "sample")).unwrap();
+    fs::write(dir.join("day25.txt"), sample(25, "sample")).unwrap();
+    fs::write(dir.join("notes.txt"), "not an input").unwrap();
+
+    let out = batch(&dir, 2023, Duration::from_secs(10)).unwrap();
+
+    assert!(out.starts_with("day1:\nfile"), "{out}");
+    assert!(out.contains("day1-a.txt | 142   "), "{out}");
+    assert!(out.contains("\nday6:\n"), "{out}");
+    assert!(out.contains("\nday25:\n"), "{out}");
+    assert!(!out.contains("day25.txt part2"), "{out}");
+    assert!(!out.contains("notes"), "{out}");
+    assert!(
+        out.contains("errors:\n  day1-b.txt part1: no digits in 'eightwothree'"),
+        "{out}"
+    );
+    assert!(
+        out.contains("wrong answers:\n  day1-a.txt part2: expected 143, got 142\n"),
+        "{out}"
+    );
+    assert!(out.contains("wrong day:\n  day6.txt: the input does not look like the input of day6, it looks like day2\n"), "{out}");
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,292 @@
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::registry::Solver;
+
+/// How often a long search saves its progress.
+const INTERVAL: Duration = Duration::from_secs(5);
+
+const HEADER: &str = "aoc checkpoint";
+
+/// The parts that save their progress. The others have nothing to resume.
+const PARTS: &[(u32, u32, u32)] = &[(2023, 23, 1), (2023, 23, 2), (2023, 25, 1)];
+
+/// Returns true if the part saves its progress to a checkpoint.
+pub fn supported(year: u32, day: u32, part: u32) -> bool {
+    PARTS.contains(&(year, day, part))
+}
+
+/// The progress of a search, as written to a checkpoint file.
+pub trait State: Sized {
+    fn encode(&self) -> String;
+    fn decode(s: &str) -> Result<Self>;
+}
+
+/// Where the solver keeps its checkpoint, and whether it picks up from it.
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub path: PathBuf,
+    pub resume: bool,
+    pub interval: Duration,
+}
+
+impl Config {
+    pub fn new(path: PathBuf, resume: bool) -> Config {
+        Config {
+            path,
+            resume,
+            interval: INTERVAL,
+        }
+    }
+}
+
+/// The checkpoint of the solver running on this thread. The key tells apart
+/// the checkpoints of other parts and inputs.
+struct Current {
+    config: Config,
+    key: String,
+    last_save: Instant,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Current>> = const { RefCell::new(None) };
+}
+
+/// Returns the state saved by an earlier run, if this run resumes it. Fails
+/// if the checkpoint belongs to another part or input.
+///
+/// Example:
+///    let mut progress = checkpoint::resume()?.unwrap_or(Progress::new());
+pub fn resume<T: State>() -> Result<Option<T>> {
+    CURRENT.with(|current| {
+        let current = current.borrow();
+        let Some(current) = current.as_ref().filter(|c| c.config.resume) else {
+            return Ok(None);
+        };
+
+        let path = &current.config.path;
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return Ok(None);
+        };
+
+        let invalid = || format!("{}: not a checkpoint", path.display());
+        let (header, rest) = content.split_once('\n').ok_or_else(invalid)?;
+        let (key, state) = rest.split_once('\n').ok_or_else(invalid)?;
+        if header != HEADER {
+            return Err(invalid());
+        }
+        if key != current.key {
+            return Err(format!(
+                "{}: the checkpoint is of {key}, not of {}",
+                path.display(),
+                current.key
+            ));
+        }
+
+        T::decode(state).map(Some)
+    })
+}
+
+/// Saves the state, at most once per interval. Does nothing without a
+/// checkpoint file.
+pub fn save<T: State>(state: &T) -> Result<()> {
+    CURRENT.with(|current| {
+        let mut current = current.borrow_mut();
+        let Some(current) = current.as_mut() else {
+            return Ok(());
+        };
+        if current.last_save.elapsed() < current.config.interval {
+            return Ok(());
+        }
+
+        write(&current.config.path, &current.key, &state.encode())?;
+        current.last_save = Instant::now();
+
+        Ok(())
+    })
+}
+
+/// Writes the file next to the checkpoint first, so an interrupted write
+/// never leaves half a checkpoint.
+fn write(path: &Path, key: &str, state: &str) -> Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+
+    std::fs::write(&tmp, format!("{HEADER}\n{key}\n{state}"))
+        .and_then(|_| std::fs::rename(&tmp, path))
+        .map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Runs `f` with the checkpoint of `key`.
+pub fn with<T>(config: &Config, key: &str, f: impl FnOnce() -> T) -> T {
+    let current = Current {
+        config: config.clone(),
+        key: key.to_string(),
+        last_save: Instant::now(),
+    };
+
+    let previous = CURRENT.with(|c| c.replace(Some(current)));
+    let result = f();
+    CURRENT.with(|c| c.replace(previous));
+
+    result
+}
+
+/// Wraps a solver so it saves its progress to the checkpoint, and removes the
+/// checkpoint once it has the answer.
+pub fn wrap(solver: &Solver, config: Config) -> Solver {
+    let solve = solver.solve.clone();
+    let part = format!("{} day{} part{}", solver.year, solver.day, solver.part);
+
+    Solver {
+        solve: Arc::new(move |input| {
+            let key = format!("{part} input {:016x}", fingerprint(input));
+            let answer = with(&config, &key, || solve(input))?;
+            remove(&config.path, &key);
+
+            Ok(answer)
+        }),
+        ..solver.clone()
+    }
+}
+
+/// Removes the checkpoint of `key`. Anything else at the path, e.g. a file
+/// given as the checkpoint by mistake, is left alone.
+fn remove(path: &Path, key: &str) {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return;
+    };
+
+    if content.lines().take(2).eq([HEADER, key]) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// A hash of the input that does not change between builds (FNV-1a).
+pub fn fingerprint(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Reads the numbers of a state that is written as numbers separated by
+/// spaces.
+pub fn numbers<T: FromStr, const N: usize>(s: &str) -> Result<[T; N]> {
+    let numbers = s
+        .split_whitespace()
+        .map(|n| n.parse::<T>().ok())
+        .collect::<Option<Vec<_>>>()
+        .ok_or(format!("invalid checkpoint state: {s}"))?;
+
+    numbers
+        .try_into()
+        .map_err(|_| format!("expected {N} numbers in the checkpoint state: {s}"))
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[cfg(test)]
+#[derive(Debug, PartialEq)]
+struct Count(i64);
+
+#[cfg(test)]
+impl State for Count {
+    fn encode(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn decode(s: &str) -> Result<Count> {
+        let [n] = numbers(s)?;
+        Ok(Count(n))
+    }
+}
+
+#[test]
+fn test_save_and_resume() {
+    let path = std::env::temp_dir().join(format!("aoc-checkpoint-{}", std::process::id()));
+    let config = Config {
+        path: path.clone(),
+        resume: true,
+        interval: Duration::ZERO,
+    };
+
+    assert_eq!(Ok(None), with(&config, "a", resume::<Count>));
+    with(&config, "a", || save(&Count(42))).unwrap();
+    assert_eq!(Ok(Some(Count(42))), with(&config, "a", resume::<Count>));
+    assert!(with(&config, "b", resume::<Count>).is_err());
+
+    // Without resuming the checkpoint is only written.
+    let config = Config {
+        resume: false,
+        ..config
+    };
+    assert_eq!(Ok(None), with(&config, "a", resume::<Count>));
+
+    // Nothing is saved without a checkpoint.
+    save(&Count(7)).unwrap();
+    assert_eq!(Ok(None), resume::<Count>());
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_wrap() {
+    let path = std::env::temp_dir().join(format!("aoc-checkpoint-wrap-{}", std::process::id()));
+    let config = Config {
+        path: path.clone(),
+        resume: false,
+        interval: Duration::ZERO,
+    };
+    let solver = Solver {
+        year: 2023,
+        day: 25,
+        part: 1,
+        name: "saving".to_string(),
+        solve: Arc::new(|input| {
+            save(&Count(1))?;
+            Ok(input.len().into())
+        }),
+    };
+
+    // The checkpoint of the run is removed once it has the answer.
+    (wrap(&solver, config.clone()).solve)("abc").unwrap();
+    assert!(!path.exists());
+
+    // Other files are not.
+    let solver = Solver {
+        solve: Arc::new(|input| Ok(input.len().into())),
+        ..solver
+    };
+    std::fs::write(&path, "my notes").unwrap();
+    (wrap(&solver, config.clone()).solve)("abc").unwrap();
+    assert_eq!("my notes", std::fs::read_to_string(&path).unwrap());
+
+    std::fs::write(&path, format!("{HEADER}\n2023 day23 part2 input 0\n1")).unwrap();
+    (wrap(&solver, config).solve)("abc").unwrap();
+    assert!(path.exists());
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_supported() {
+    assert!(supported(2023, 25, 1));
+    assert!(!supported(2023, 1, 1));
+}
+
+#[test]
+fn test_numbers() {
+    assert_eq!(Ok([3, -4]), numbers("3 -4"));
+    assert!(numbers::<i64, 2>("3").is_err());
+    assert!(numbers::<u64, 1>("-3").is_err());
+}
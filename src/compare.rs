@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use itertools::Itertools;
+
+use crate::{
+    registry::Solver,
+    runner::{self, Outcome},
+    util,
+};
+
+/// Runs every implementation on the input and renders their answers and their
+/// timings relative to the fastest one, followed by whether they agree.
+/// Returns false as well if the answers differ or an implementation failed.
+pub fn compare(solvers: &[Solver], input: &str, timeout: Duration) -> (String, bool) {
+    let outcomes = solvers
+        .iter()
+        .map(|s| (s.name.as_str(), runner::run_with_timeout(s, input, timeout)))
+        .collect::<Vec<_>>();
+
+    render(&outcomes)
+}
+
+fn render(outcomes: &[(&str, Outcome)]) -> (String, bool) {
+    let fastest = outcomes
+        .iter()
+        .filter(|(_, o)| o.answer.is_ok())
+        .map(|(_, o)| o.elapsed)
+        .min()
+        .unwrap_or(Duration::ZERO);
+
+    let header = ["impl", "answer", "time", "relative"].map(|h| h.to_string());
+    let rows = outcomes
+        .iter()
+        .map(|(name, o)| match &o.answer {
+            Ok(answer) => vec![
+                name.to_string(),
+                answer.to_string(),
+                runner::format_duration(o.elapsed),
+                format!(
+                    "{:.2}x",
+                    o.elapsed.as_secs_f64() / fastest.as_secs_f64().max(1e-9)
+                ),
+            ],
+            Err(_) => vec![
+                name.to_string(),
+                "error".to_string(),
+                "-".to_string(),
+                "-".to_string(),
+            ],
+        })
+        .collect::<Vec<_>>();
+
+    let mut out = util::text_table(&header, &rows);
+    out.push('\n');
+
+    let errors = outcomes
+        .iter()
+        .filter_map(|(name, o)| Some(format!("{name}: {}", o.answer.as_ref().err()?)))
+        .collect::<Vec<_>>();
+    let answers = outcomes
+        .iter()
+        .filter_map(|(_, o)| o.answer.as_ref().ok())
+        .unique()
+        .collect::<Vec<_>>();
+
+    let agree = errors.is_empty() && answers.len() == 1;
+
+    match answers.as_slice() {
+        [_] if outcomes.len() == 1 => out.push_str("there is only one implementation to compare\n"),
+        [answer] if errors.is_empty() => out.push_str(&format!(
+            "all {} implementations agree on {answer}\n",
+            outcomes.len()
+        )),
+        [] | [_] => out.push_str("some implementations failed\n"),
+        _ => out.push_str(&format!(
+            "the implementations disagree: {}\n",
+            outcomes
+                .iter()
+                .filter_map(|(name, o)| Some(format!("{name} = {}", o.answer.as_ref().ok()?)))
+                .join(", ")
+        )),
+    }
+    for error in errors {
+        out.push_str(&format!("  {error}\n"));
+    }
+
+    (out, agree)
+}
+
+#[cfg(test)]
+fn outcome(answer: std::result::Result<&str, &str>, millis: u64) -> Outcome {
+    Outcome {
+        answer: answer
+            .map(crate::answer::Answer::parse)
+            .map_err(|e| crate::runner::Failure::Error(e.to_string())),
+        elapsed: Duration::from_millis(millis),
+        allocs: Default::default(),
+    }
+}
+
+#[test]
+fn test_render() {
+    let (out, agree) = render(&[
+        ("default", outcome(Ok("46"), 2)),
+        ("brute-force", outcome(Ok("46"), 30)),
+    ]);
+    assert!(agree);
+    assert_eq!(
+        "impl        | answer | time   | relative
+----------- | ------ | ------ | --------
+default     | 46     | 2.0ms  | 1.00x
+brute-force | 46     | 30.0ms | 15.00x
+
+all 2 implementations agree on 46
+",
+        out
+    );
+
+    let (out, agree) = render(&[
+        ("default", outcome(Ok("46"), 2)),
+        ("brute-force", outcome(Ok("45"), 30)),
+    ]);
+    assert!(!agree);
+    assert!(
+        out.ends_with("the implementations disagree: default = 46, brute-force = 45\n"),
+        "{out}"
+    );
+
+    let (out, agree) = render(&[
+        ("default", outcome(Ok("46"), 2)),
+        (
+            "brute-force",
+            outcome(Err("timed out after 10.00s"), 10_000),
+        ),
+    ]);
+    assert!(!agree);
+    assert!(out.contains("brute-force | error  | -"), "{out}");
+    assert!(
+        out.ends_with("some implementations failed\n  brute-force: timed out after 10.00s\n"),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_compare_examples() {
+    use crate::{fixtures, registry};
+    use std::path::Path;
+
+    // Every implementation has to give the answers of the examples, not only
+    // the default one.
+    for f in fixtures::load_all(Path::new(fixtures::EXAMPLES_DIR)).unwrap() {
+        for (&part, expected) in f.expected.iter() {
+            let solvers = registry::implementations(f.year, f.day, part);
+            if solvers.len() < 2 {
+                continue;
+            }
+
+            let (out, agree) = compare(&solvers, &f.input, Duration::from_secs(10));
+            assert!(agree, "day{} part{part} ({}):\n{out}", f.day, f.name);
+            assert!(out.contains(&format!("agree on {expected}\n")), "{out}");
+        }
+    }
+}
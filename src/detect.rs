@@ -0,0 +1,234 @@
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+/// Describes the format of the input of a day. An input matches if every line
+/// matches one of the `lines` patterns, and each of the `required` patterns
+/// matches at least one line. Empty lines are kept, so days with sections
+/// must allow `^$`.
+struct Fingerprint {
+    year: u32,
+    day: u32,
+    lines: &'static [&'static str],
+    required: &'static [&'static str],
+}
+
+const FINGERPRINTS: &[Fingerprint] = &[
+    fingerprint(2023, 1, &[r"^[a-z\d]+$"], &[r"[a-z]"]),
+    fingerprint(2023, 2, &[r"^Game \d+: \d+ [a-z]+([,;] \d+ [a-z]+)*$"], &[]),
+    fingerprint(2023, 3, &[r"^[\d.*#+$/=%@&-]+$"], &[r"\d", r"[^\d.]"]),
+    fingerprint(2023, 4, &[r"^Card +\d+:( +\d+)+ \|( +\d+)+$"], &[]),
+    fingerprint(
+        2023,
+        5,
+        &[
+            r"^seeds:( \d+)+$",
+            r"^[a-z]+-to-[a-z]+ map:$",
+            r"^\d+ \d+ \d+$",
+            r"^$",
+        ],
+        &[r"^seeds:", r"map:$"],
+    ),
+    fingerprint(
+        2023,
+        6,
+        &[r"^Time:( +\d+)+$", r"^Distance:( +\d+)+$"],
+        &[r"^Time:", r"^Distance:"],
+    ),
+    fingerprint(2023, 7, &[r"^[2-9TJQKA]{5} \d+$"], &[]),
+    fingerprint(
+        2023,
+        8,
+        &[r"^[LR]+$", r"^\w{3} = \(\w{3}, \w{3}\)$", r"^$"],
+        &[r"^[LR]+$", r" = "],
+    ),
+    fingerprint(2023, 9, &[r"^-?\d+( -?\d+)+$"], &[]),
+    fingerprint(2023, 10, &[r"^[|\-LJ7F.S]+$"], &[r"S"]),
+    fingerprint(2023, 11, &[r"^[.#]+$"], &[]),
+    fingerprint(2023, 12, &[r"^[.#?]+ \d+(,\d+)*$"], &[]),
+    fingerprint(2023, 13, &[r"^[.#]+$", r"^$"], &[r"^$"]),
+    fingerprint(2023, 14, &[r"^[.#O]+$"], &[r"O"]),
+    fingerprint(2023, 15, &[r"^[a-z]+(-|=\d)(,[a-z]+(-|=\d))*$"], &[]),
+    fingerprint(2023, 16, &[r"^[.|\-/\\]+$"], &[r"[/\\]"]),
+    fingerprint(2023, 17, &[r"^\d+$"], &[]),
+    fingerprint(2023, 18, &[r"^[UDLR] \d+ \(#[0-9a-f]{6}\)$"], &[]),
+    fingerprint(
+        2023,
+        19,
+        &[
+            r"^[a-z]+\{([xmas][<>]\d+:[a-zAR]+,)+[a-zAR]+\}$",
+            r"^\{x=\d+,m=\d+,a=\d+,s=\d+\}$",
+            r"^$",
+        ],
+        &[r"^[a-z]+\{", r"^\{x="],
+    ),
+    fingerprint(
+        2023,
+        20,
+        &[r"^(broadcaster|[%&][a-z]+) -> [a-z]+(, [a-z]+)*$"],
+        &[r"^broadcaster "],
+    ),
+    fingerprint(2023, 21, &[r"^[.#S]+$"], &[r"S"]),
+    fingerprint(2023, 22, &[r"^\d+,\d+,\d+~\d+,\d+,\d+$"], &[]),
+    fingerprint(2023, 23, &[r"^[#.<>^v]+$"], &[r"[<>^v]"]),
+    fingerprint(
+        2023,
+        24,
+        &[r"^-?\d+, +-?\d+, +-?\d+ +@ +-?\d+, +-?\d+, +-?\d+$"],
+        &[],
+    ),
+    fingerprint(2023, 25, &[r"^[a-z]+:( [a-z]+)+$"], &[]),
+];
+
+const fn fingerprint(
+    year: u32,
+    day: u32,
+    lines: &'static [&'static str],
+    required: &'static [&'static str],
+) -> Fingerprint {
+    Fingerprint {
+        year,
+        day,
+        lines,
+        required,
+    }
+}
+
+/// The compiled `lines` and `required` patterns of a fingerprint.
+struct Patterns {
+    lines: Vec<Regex>,
+    required: Vec<Regex>,
+}
+
+/// Returns the days of the year whose input format matches the input.
+pub fn detect(year: u32, input: &str) -> Vec<u32> {
+    compiled()
+        .iter()
+        .filter(|(fp, patterns)| fp.year == year && matches(patterns, input))
+        .map(|(fp, _)| fp.day)
+        .collect()
+}
+
+/// Returns a warning if the input does not look like the input of the day.
+/// Days without a fingerprint are never warned about.
+pub fn check(year: u32, day: u32, input: &str) -> Option<String> {
+    let (_, patterns) = compiled()
+        .iter()
+        .find(|(fp, _)| fp.year == year && fp.day == day)?;
+
+    if matches(patterns, input) {
+        return None;
+    }
+
+    let days = detect(year, input);
+    if days.is_empty() {
+        Some(format!(
+            "the input does not look like the input of day{day}"
+        ))
+    } else {
+        Some(format!(
+            "the input does not look like the input of day{day}, it looks like {}",
+            describe(&days)
+        ))
+    }
+}
+
+/// Lists the days as "day2", "day11 or day13", etc.
+pub fn describe(days: &[u32]) -> String {
+    let days = days.iter().map(|d| format!("day{d}")).collect::<Vec<_>>();
+
+    match days.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+        None => "no known day".to_string(),
+    }
+}
+
+fn compiled() -> &'static [(&'static Fingerprint, Patterns)] {
+    static COMPILED: OnceLock<Vec<(&'static Fingerprint, Patterns)>> = OnceLock::new();
+
+    let compile = |patterns: &[&str]| patterns.iter().map(|p| Regex::new(p).unwrap()).collect();
+
+    COMPILED.get_or_init(|| {
+        FINGERPRINTS
+            .iter()
+            .map(|fp| {
+                let patterns = Patterns {
+                    lines: compile(fp.lines),
+                    required: compile(fp.required),
+                };
+                (fp, patterns)
+            })
+            .collect()
+    })
+}
+
+fn matches(patterns: &Patterns, input: &str) -> bool {
+    let lines = input
+        .trim_end()
+        .lines()
+        .map(|l| l.trim_end())
+        .collect::<Vec<_>>();
+
+    !lines.is_empty()
+        && lines
+            .iter()
+            .all(|l| patterns.lines.iter().any(|p| p.is_match(l)))
+        && patterns
+            .required
+            .iter()
+            .all(|p| lines.iter().any(|l| p.is_match(l)))
+}
+
+#[test]
+fn test_detect_examples() {
+    use crate::fixtures;
+    use std::path::Path;
+
+    for f in fixtures::load_all(Path::new(fixtures::EXAMPLES_DIR)).unwrap() {
+        assert_eq!(
+            vec![f.day],
+            detect(f.year, &f.input),
+            "{} day{} ({})",
+            f.year,
+            f.day,
+            f.name
+        );
+    }
+}
+
+#[test]
+fn test_check() {
+    let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n";
+
+    assert_eq!(None, check(2023, 2, input));
+    assert_eq!(
+        Some("the input does not look like the input of day4, it looks like day2".to_string()),
+        check(2023, 4, input)
+    );
+    assert_eq!(
+        Some("the input does not look like the input of day4".to_string()),
+        check(2023, 4, "hello world")
+    );
+}
+
+#[test]
+fn test_describe() {
+    assert_eq!("day2", describe(&[2]));
+    assert_eq!("day11 or day13", describe(&[11, 13]));
+    assert_eq!("day1, day11 or day13", describe(&[1, 11, 13]));
+    assert_eq!("no known day", describe(&[]));
+}
+
+#[test]
+fn test_detect_inputs() {
+    use crate::registry;
+
+    for day in 1..=25 {
+        let Ok(input) = std::fs::read_to_string(registry::input_path(2023, day)) else {
+            continue;
+        };
+
+        assert_eq!(vec![day], detect(2023, &input), "day{day}");
+    }
+}
@@ -0,0 +1,70 @@
+// The exit codes of the command line, so scripts can tell failures apart.
+
+use crate::runner::Failure;
+
+/// Anything that does not fit one of the codes below, e.g. a file could not be
+/// written or a check found answers that disagree.
+pub const FAILURE: i32 = 1;
+
+/// The arguments were wrong, e.g. an unknown day. clap uses the same code.
+pub const USAGE: i32 = 2;
+
+/// The input file could not be read.
+pub const INPUT_NOT_FOUND: i32 = 3;
+
+/// The input could not be parsed, by the solver or e.g. to simulate it or to
+/// detect its day.
+pub const PARSE: i32 = 4;
+
+/// The solver found no answer in the input, or panicked.
+pub const SOLVER: i32 = 5;
+
+/// The solver did not finish in time.
+pub const TIMEOUT: i32 = 6;
+
+/// Prints the error to stderr and exits with the code.
+pub fn fail(code: i32, err: impl std::fmt::Display) -> ! {
+    eprintln!("{err}");
+    std::process::exit(code)
+}
+
+/// Picks the exit code for a run that did not give an answer.
+pub fn code(failure: &Failure) -> i32 {
+    match failure {
+        Failure::Parse(_) => PARSE,
+        Failure::Error(_) | Failure::Panic { .. } => SOLVER,
+        Failure::Timeout(_) => TIMEOUT,
+        Failure::Spawn(_) => FAILURE,
+    }
+}
+
+#[test]
+fn test_code() {
+    assert_eq!(
+        PARSE,
+        code(&Failure::Parse("invalid game: garbage".to_string()))
+    );
+    assert_eq!(
+        SOLVER,
+        code(&Failure::Error("ZZZ is never reached from AAA".to_string()))
+    );
+    assert_eq!(
+        SOLVER,
+        code(&Failure::Panic {
+            location: "src/y2023/day1.rs:10:5".to_string(),
+            message: "oops".to_string(),
+        })
+    );
+    assert_eq!(
+        TIMEOUT,
+        code(&Failure::Timeout(std::time::Duration::from_secs(1)))
+    );
+}
+
+#[test]
+fn test_code_of_malformed_input() {
+    let solver = crate::registry::find(2023, 2, 1).unwrap();
+    let failure = crate::runner::run(&solver, "garbage\n").answer.unwrap_err();
+
+    assert_eq!(PARSE, code(&failure));
+}
@@ -0,0 +1,169 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use crate::{answer::Answer, util};
+
+/// Example inputs live in `examples/<year>/dayN/<name>.txt`. The expected
+/// answers for an example are listed in `examples/<year>/dayN/<name>.expected`,
+/// one per line:
+///
+///    part1 = 142
+///    part2 = 281
+///
+/// Parts without a line are not checked, and the file can be left out
+/// completely for examples that are only used by unit tests.
+pub const EXAMPLES_DIR: &str = "./examples";
+
+pub struct Fixture {
+    pub year: u32,
+    pub day: u32,
+    pub name: String,
+    pub input: String,
+    pub expected: BTreeMap<u32, Answer>,
+}
+
+/// Loads the fixtures for all years and days found in `dir`.
+#[cfg(test)]
+pub fn load_all(dir: &Path) -> Result<Vec<Fixture>> {
+    let mut fixtures = Vec::new();
+
+    for year in numbered_dirs(dir, "")? {
+        for day in numbered_dirs(&dir.join(year.to_string()), "day")? {
+            fixtures.extend(load_day(dir, year, day)?);
+        }
+    }
+
+    Ok(fixtures)
+}
+
+/// Loads the fixtures for a single day, sorted by name. A day without any
+/// examples has no fixtures.
+pub fn load_day(dir: &Path, year: u32, day: u32) -> Result<Vec<Fixture>> {
+    let day_dir = dir.join(year.to_string()).join(format!("day{day}"));
+    if !day_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut inputs = fs::read_dir(&day_dir)
+        .map_err(|err| format!("{}: {err}", day_dir.display()))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "txt"))
+        .collect::<Vec<_>>();
+
+    inputs.sort();
+
+    inputs
+        .into_iter()
+        .map(|path| {
+            Ok(Fixture {
+                year,
+                day,
+                name: path.file_stem().unwrap().to_string_lossy().to_string(),
+                input: read(&path)?,
+                expected: read_expected(&path.with_extension("expected"))?,
+            })
+        })
+        .collect()
+}
+
+/// Reads a file of expected answers. A missing file has no answers.
+pub fn read_expected(path: &Path) -> Result<BTreeMap<u32, Answer>> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+
+    parse_expected(&read(path)?).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Returns the numbers of the directories in `dir` that are named `<prefix>N`,
+/// in ascending order.
+#[cfg(test)]
+fn numbered_dirs(dir: &Path, prefix: &str) -> Result<Vec<u32>> {
+    let mut numbers = fs::read_dir(dir)
+        .map_err(|err| format!("{}: {err}", dir.display()))?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            name.strip_prefix(prefix)?.parse::<u32>().ok()
+        })
+        .collect::<Vec<_>>();
+
+    numbers.sort();
+
+    Ok(numbers)
+}
+
+fn parse_expected(s: &str) -> Result<BTreeMap<u32, Answer>> {
+    util::non_empty_lines(s)
+        .filter(|l| !l.starts_with('#'))
+        .map(|l| {
+            let (part, answer) = l
+                .split_once('=')
+                .ok_or(format!("expected 'partN = answer', got '{l}'"))?;
+
+            let part = part
+                .trim()
+                .strip_prefix("part")
+                .and_then(|p| p.parse().ok())
+                .ok_or(format!("invalid part: '{}'", part.trim()))?;
+
+            if answer.trim().is_empty() {
+                return Err(format!("part{part} has no answer"));
+            }
+
+            Ok((part, Answer::parse(answer)))
+        })
+        .collect()
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[test]
+fn test_examples() {
+    use crate::{registry, runner};
+
+    let fixtures = load_all(Path::new(EXAMPLES_DIR)).unwrap();
+    assert!(!fixtures.is_empty());
+
+    let mut failures = Vec::new();
+
+    for f in fixtures.iter() {
+        for (&part, expected) in f.expected.iter() {
+            let id = format!("{} day{} part{part} ({})", f.year, f.day, f.name);
+
+            let Some(solver) = registry::find(f.year, f.day, part) else {
+                failures.push(format!("{id}: no solver registered"));
+                continue;
+            };
+
+            match runner::run(&solver, &f.input).answer {
+                Ok(answer) if &answer == expected => {}
+                Ok(answer) => failures.push(format!("{id}: expected {expected}, got {answer}")),
+                Err(err) => failures.push(format!("{id}: {err}")),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
+
+#[test]
+fn test_parse_expected() {
+    let expected = parse_expected("\n# comment\npart1 = 142\npart2=  281 \n").unwrap();
+
+    assert_eq!(Some(&Answer::Int(142)), expected.get(&1));
+    assert_eq!(Some(&Answer::Int(281)), expected.get(&2));
+    assert!(parse_expected("part1: 142").is_err());
+    assert!(parse_expected("partX = 142").is_err());
+    assert!(parse_expected("part1 = 142\npart2 = \n").is_err());
+}
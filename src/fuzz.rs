@@ -0,0 +1,305 @@
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{
+    checkpoint, fixtures, oracle, registry,
+    runner::{self, Failure, Outcome},
+};
+
+/// Inputs that made a solver panic or hang are kept in
+/// `fuzz/corpus/<year>/dayN/`, and the tests check that they no longer do.
+pub const CORPUS_DIR: &str = "./fuzz/corpus";
+
+/// Bytes that mean something in at least one of the input formats.
+const SPECIAL_BYTES: &[u8] = b"0123456789 \n,:;-=#.@~()<>{}|/\\SOLRAZ";
+
+/// Values that tend to break the assumptions of a parser.
+const TOKENS: &[&str] = &[
+    "0",
+    "-1",
+    "99999999999999999999",
+    "\n",
+    "\n\n",
+    " ",
+    ",",
+    ": ",
+    " -> ",
+    " @ ",
+    "~",
+    " | ",
+];
+
+/// A part that panicked or did not finish on an input.
+pub struct Crash {
+    pub day: u32,
+    pub part: u32,
+    pub error: String,
+    pub input: String,
+}
+
+/// Mutates the examples and generated inputs of the day `cases` times, and runs
+/// both parts on each mutated input. Returns the first crash of each part at
+/// each location, with the input shrunk to as few lines as still crash there.
+pub fn fuzz(year: u32, day: u32, cases: usize, seed: u64, timeout: Duration) -> Result<Vec<Crash>> {
+    let seeds = seeds(year, day)?;
+    if seeds.is_empty() {
+        return Err(format!(
+            "day{day} has no examples or generator to start from"
+        ));
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut crashes = Vec::new();
+
+    for part in [1, 2] {
+        let Some(solver) = registry::find(year, day, part) else {
+            continue;
+        };
+        let mut seen = HashSet::new();
+
+        for _ in 0..cases {
+            let seed = seeds.choose(&mut rng).unwrap();
+            let input = mutate(&mut rng, seed);
+
+            let Some(error) = crash(&runner::run_with_timeout(&solver, &input, timeout)) else {
+                continue;
+            };
+            if !seen.insert(location(&error)) {
+                continue;
+            }
+
+            // A solver that hangs keeps running in the background, so only
+            // panics are worth running again to shrink.
+            let input = if let Failure::Panic { .. } = error {
+                oracle::shrink(&input, |input| {
+                    crash(&runner::run(&solver, input))
+                        .is_some_and(|e| location(&e) == location(&error))
+                })
+            } else {
+                input
+            };
+
+            crashes.push(Crash {
+                day,
+                part,
+                error: error.to_string(),
+                input,
+            });
+        }
+    }
+
+    Ok(crashes)
+}
+
+/// Fuzzes the day, or every day of the year, and saves each crash to the
+/// corpus. Returns a line per part and whether nothing crashed.
+pub fn fuzz_all(
+    year: u32,
+    day: Option<u32>,
+    cases: usize,
+    seed: u64,
+    timeout: Duration,
+) -> Result<(String, bool)> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => (1..=25)
+            .filter(|&d| registry::find(year, d, 1).is_some())
+            .collect(),
+    };
+
+    let mut out = String::new();
+    let mut ok = true;
+
+    for day in days {
+        let crashes = fuzz(year, day, cases, seed, timeout)?;
+        ok &= crashes.is_empty();
+
+        if crashes.is_empty() {
+            out.push_str(&format!(
+                "day{day}: no crashes in {cases} inputs per part\n"
+            ));
+        }
+        for crash in crashes {
+            let path = save(year, &crash)?;
+            out.push_str(&format!(
+                "day{} part{}: {}\n  saved to {}\n",
+                crash.day,
+                crash.part,
+                crash.error,
+                path.display()
+            ));
+        }
+    }
+
+    Ok((out, ok))
+}
+
+/// Returns the error of the outcome if the solver panicked or timed out. Any
+/// other error is the expected way to reject an input.
+pub fn crash(outcome: &Outcome) -> Option<Failure> {
+    match outcome.answer.as_ref().err()? {
+        err @ (Failure::Panic { .. } | Failure::Timeout(_)) => Some(err.clone()),
+        _ => None,
+    }
+}
+
+/// The part of a crash that tells crashes apart, e.g. "panicked at
+/// src/y2023/day5.rs:12:5".
+fn location(error: &Failure) -> String {
+    match error {
+        Failure::Panic { location, .. } => format!("panicked at {location}"),
+        _ => "timed out".to_string(),
+    }
+}
+
+fn seeds(year: u32, day: u32) -> Result<Vec<String>> {
+    let mut seeds = fixtures::load_day(Path::new(fixtures::EXAMPLES_DIR), year, day)?
+        .into_iter()
+        .map(|f| f.input)
+        .filter(|input| !input.trim().is_empty())
+        .collect::<Vec<_>>();
+
+    if let Some(generate) = registry::generator(year, day) {
+        let mut rng = StdRng::seed_from_u64(0);
+        seeds.extend((1..=4).map(|size| generate(&mut rng, size)));
+    }
+
+    Ok(seeds)
+}
+
+/// Makes a few random changes to the bytes of the input. The result does not
+/// have to be valid UTF-8 before it is turned into a string, so the replaced
+/// bytes show up as replacement characters.
+fn mutate(rng: &mut StdRng, input: &str) -> String {
+    let mut bytes = input.as_bytes().to_vec();
+
+    for _ in 0..rng.gen_range(1..=4) {
+        let len = bytes.len();
+        let at = rng.gen_range(0..=len);
+
+        match rng.gen_range(0..7) {
+            0 if at < len => {
+                bytes[at] = match rng.gen_range(0..3) {
+                    0 => bytes[rng.gen_range(0..len)],
+                    1 => *SPECIAL_BYTES.choose(rng).unwrap(),
+                    _ => rng.gen(),
+                }
+            }
+            1 => {
+                let end = (at + rng.gen_range(1..=8)).min(len);
+                bytes.drain(at..end);
+            }
+            2 if len > 0 => {
+                let from = rng.gen_range(0..len);
+                let copy = bytes[from..(from + rng.gen_range(1..=16)).min(len)].to_vec();
+                bytes.splice(at..at, copy);
+            }
+            3 => {
+                let token = TOKENS.choose(rng).unwrap();
+                bytes.splice(at..at, token.bytes());
+            }
+            4 => bytes.truncate(at),
+            5 => {
+                let mut lines = bytes
+                    .split(|&b| b == b'\n')
+                    .map(|l| l.to_vec())
+                    .collect::<Vec<_>>();
+                let (a, b) = (rng.gen_range(0..lines.len()), rng.gen_range(0..lines.len()));
+                if rng.gen_bool(0.5) {
+                    lines.swap(a, b);
+                } else {
+                    let line = lines[a].clone();
+                    lines.insert(b, line);
+                }
+                bytes = lines.join(&b'\n');
+            }
+            _ => bytes.insert(at, rng.gen()),
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn save(year: u32, crash: &Crash) -> Result<PathBuf> {
+    let dir = PathBuf::from(CORPUS_DIR)
+        .join(year.to_string())
+        .join(format!("day{}", crash.day));
+    fs::create_dir_all(&dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+
+    // The name only depends on the input, so a crash found again is saved to
+    // the same file by every build.
+    let path = dir.join(format!(
+        "{:016x}.txt",
+        checkpoint::fingerprint(&crash.input)
+    ));
+
+    fs::write(&path, &crash.input).map_err(|err| format!("{}: {err}", path.display()))?;
+
+    Ok(path)
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[test]
+fn test_mutate() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    let inputs = (0..100)
+        .map(|_| mutate(&mut rng, "Game 1: 3 blue, 4 red\n"))
+        .collect::<HashSet<_>>();
+
+    assert!(inputs.len() > 50, "{inputs:?}");
+}
+
+#[test]
+fn test_corpus() {
+    for day in 1..=25 {
+        let dir = PathBuf::from(CORPUS_DIR)
+            .join("2023")
+            .join(format!("day{day}"));
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for path in entries.map(|e| e.unwrap().path()) {
+            let input = fs::read_to_string(&path).unwrap();
+            assert_eq!(
+                format!("{:016x}.txt", checkpoint::fingerprint(&input)),
+                path.file_name().unwrap().to_string_lossy(),
+            );
+
+            for part in [1, 2] {
+                let solver = registry::find(2023, day, part).unwrap();
+                let outcome = runner::run_with_timeout(&solver, &input, Duration::from_secs(10));
+
+                assert_eq!(None, crash(&outcome), "{} part{part}", path.display());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fuzz() {
+    for day in 1..=25 {
+        let crashes = fuzz(2023, day, 20, 1, Duration::from_secs(10)).unwrap();
+
+        assert!(
+            crashes.is_empty(),
+            "day{day} part{}: {}\n{}",
+            crashes[0].part,
+            crashes[0].error,
+            crashes[0].input
+        );
+    }
+}
@@ -0,0 +1,42 @@
+// The solvers and the commands of the command line tool, which is built from
+// main.rs. With the python feature this is also the Python extension module,
+// see src/python.rs.
+
+pub mod alloc;
+pub mod animate;
+pub mod answer;
+pub mod batch;
+pub mod checkpoint;
+pub mod compare;
+pub mod detect;
+pub mod exit;
+pub mod fixtures;
+pub mod fuzz;
+pub mod oracle;
+pub mod params;
+pub mod plugin;
+pub mod progress;
+pub mod registry;
+pub mod render;
+pub mod repl;
+pub mod report;
+pub mod rng;
+pub mod runner;
+pub mod scaffold;
+pub mod scale;
+pub mod serve;
+pub mod simulation;
+pub mod trace;
+pub mod watch;
+
+#[macro_use]
+pub mod util;
+
+#[cfg(feature = "python")]
+mod python;
+pub mod y2023;
+
+// The tests measure allocations as the binary does.
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
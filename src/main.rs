@@ -1,155 +1,802 @@
 extern crate clap;
 
-use clap::Parser;
+use std::{
+    io::IsTerminal,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 
-mod days;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use itertools::Itertools;
+use rand::{rngs::StdRng, SeedableRng};
 
-#[macro_use]
-mod util;
+use aoc::{
+    alloc, animate, batch, checkpoint, compare, detect, exit, fuzz, oracle, plugin,
+    progress, registry, render, repl, report, rng, runner, scaffold, scale, serve, simulation,
+    trace, watch,
+};
+
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
 
 #[derive(Parser, Debug)]
+#[command(subcommand_negates_reqs = true)]
 struct CLI {
-    /// The day to run
-    day: String,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// The year of the puzzles [default: the latest year]
+    #[arg(long, global = true)]
+    year: Option<u32>,
+
+    /// Load solvers from a shared library, they take precedence over the
+    /// built-in ones. Can be given more than once.
+    #[arg(long, global = true)]
+    plugin: Vec<PathBuf>,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Create a new day from the template and register it
+    New {
+        /// The year of the puzzle
+        year: u32,
+
+        /// The day of the puzzle
+        day: u32,
+    },
+
+    /// Run a day again whenever its input or examples change
+    Watch {
+        /// The day to run
+        day: String,
+
+        /// The part to run [default: both parts]
+        part: Option<String>,
+
+        /// How often to check for changes, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+
+    /// Serve the solvers over HTTP on localhost
+    Serve {
+        /// The port to listen on
+        #[arg(long, default_value_t = 8023)]
+        port: u16,
+    },
+
+    /// Run every dayN*.txt input in a directory and compare the results
+    Batch {
+        /// How long to wait for each solver, in seconds
+        #[arg(long, default_value_t = 10)]
+        timeout: u64,
+
+        /// The directory with the inputs
+        dir: PathBuf,
+    },
+
+    /// Run the days and write a report of the answers, timings and allocations
+    Report {
+        /// The days to run, e.g. "1-5,7" [default: all days]
+        #[arg(long)]
+        days: Option<String>,
+
+        #[arg(long, value_enum, default_value = "markdown")]
+        format: report::Format,
+
+        /// Leave the answers out of the report
+        #[arg(long)]
+        redact: bool,
+
+        /// How long to wait for each solver, in seconds
+        #[arg(long, default_value_t = 10)]
+        timeout: u64,
+
+        /// Write the report to this file instead of printing it. An existing
+        /// file only has the section between the report markers replaced.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Run every implementation of a part and check that their answers agree
+    Compare {
+        /// The day to run
+        day: String,
+
+        /// The part to run
+        part: String,
+
+        /// Read the input from this file instead of inputs/<year>/dayN.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// How long to wait for each implementation, in seconds
+        #[arg(long, default_value_t = 10)]
+        timeout: u64,
+    },
+
+    /// Check the optimised solvers against brute-force references on generated
+    /// inputs
+    Oracle {
+        /// The day to check [default: every day with a reference]
+        day: Option<String>,
+
+        /// How many inputs to generate for each solver
+        #[arg(long, default_value_t = 200)]
+        cases: usize,
+
+        /// The seed of the generated inputs
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+    },
+
+    /// Run the solvers on mutated inputs and save the inputs they panic or
+    /// hang on
+    Fuzz {
+        /// The day to fuzz [default: every day]
+        day: Option<String>,
+
+        /// How many inputs to try for each part
+        #[arg(long, default_value_t = 500)]
+        cases: usize,
+
+        /// The seed of the mutations
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+
+        /// How long a part may take on an input before it counts as hanging,
+        /// in seconds
+        #[arg(long, default_value_t = 2)]
+        timeout: u64,
+    },
+
+    /// Print a random input for a day
+    Generate {
+        /// The day to generate an input for
+        day: String,
+
+        /// How large the input should be, each day scales it differently
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+
+        /// The seed of the generated input
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+    },
+
+    /// Time a solver on generated inputs of growing sizes and estimate how
+    /// its running time grows
+    Scale {
+        /// The day to time
+        day: String,
+
+        /// The part to time
+        part: String,
+
+        /// The implementation to time [default: the default solver]
+        #[arg(long = "impl")]
+        implementation: Option<String>,
+
+        /// The largest size to generate, the sizes double from 1
+        #[arg(long, default_value_t = 256)]
+        max_size: usize,
+
+        /// The seed of the generated inputs
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+
+        /// Stop the sweep at the first size that takes longer, in seconds
+        #[arg(long, default_value_t = 10)]
+        timeout: u64,
+    },
+
+    /// Guess which day an input belongs to
+    Detect {
+        /// The input file
+        path: PathBuf,
+    },
+
+    /// Step through a day that plays out over time, e.g. the rocks of day14
+    Simulate {
+        /// The day to simulate
+        day: String,
+
+        /// Read the input from this file instead of inputs/<year>/dayN.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// The number of steps to run
+        #[arg(long, default_value_t = 10)]
+        steps: usize,
+
+        /// Print the state after every step
+        #[arg(long, conflicts_with = "cycle")]
+        dump: bool,
+
+        /// Look for the first state that repeats
+        #[arg(long)]
+        cycle: bool,
+    },
+
+    /// Play a simulated day in the terminal. Type p, s or q and Enter to
+    /// pause, step or quit
+    Animate {
+        /// The day to animate
+        day: String,
+
+        /// Read the input from this file instead of inputs/<year>/dayN.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// The number of steps to show per second
+        #[arg(long, default_value_t = 10.0)]
+        fps: f64,
+
+        /// Stop after this many steps
+        #[arg(long, default_value_t = 1_000)]
+        steps: usize,
+
+        /// Start paused, to go through the steps one at a time
+        #[arg(long)]
+        paused: bool,
+    },
+
+    /// Explore the days interactively
+    Repl,
+}
+
+#[derive(Args, Debug, Default, PartialEq)]
+struct RunArgs {
+    /// The day to run, or "auto" to detect it from the input
+    #[arg(required = true)]
+    day: Option<String>,
 
     /// The part to run
-    part: String,
+    #[arg(required = true)]
+    part: Option<String>,
+
+    /// Read the input from this file instead of inputs/<year>/dayN.txt
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Run another implementation of the part, see the compare command
+    #[arg(long = "impl")]
+    implementation: Option<String>,
+
+    /// Show the intermediate results recorded by the solver
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "table")]
+    explain: Option<ExplainFormat>,
+
+    /// The seed of the random numbers of solvers that use them, e.g. day25
+    /// [default: a random seed, which is printed so the run can be replayed]
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Give up after this many seconds [default: wait for the answer]
+    #[arg(long, conflicts_with = "explain")]
+    timeout: Option<u64>,
+
+    /// Only print the answer, without warnings or notes on stderr
+    #[arg(long, short, conflicts_with = "explain")]
+    quiet: bool,
+
+    /// Also draw the puzzle to a .ppm or .svg file, for the days that can be
+    /// drawn
+    #[arg(long, value_name = "PATH")]
+    render: Option<PathBuf>,
+
+    /// Save the progress of a long search to this file every few seconds, for
+    /// the days that support it, e.g. day23 and day25
+    #[arg(long, value_name = "PATH")]
+    checkpoint: Option<PathBuf>,
+
+    /// Continue from the progress saved in the --checkpoint file
+    #[arg(long, requires = "checkpoint")]
+    resume: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+enum ExplainFormat {
+    Table,
+    Json,
 }
 
 fn main() {
     let args = CLI::parse();
 
-    let input = std::fs::read_to_string(format!("./inputs/{}.txt", args.day)).unwrap();
-
-    println!(
-        "{}",
-        match args.day.as_str() {
-            "day1" => match args.part.as_str() {
-                "part1" => days::day1::part1(&input).unwrap(),
-                "part2" => days::day1::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day2" => match args.part.as_str() {
-                "part1" => days::day2::part1(&input).unwrap(),
-                "part2" => days::day2::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day3" => match args.part.as_str() {
-                "part1" => days::day3::part1(&input).unwrap(),
-                "part2" => days::day3::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day4" => match args.part.as_str() {
-                "part1" => days::day4::part1(&input).unwrap(),
-                "part2" => days::day4::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day5" => match args.part.as_str() {
-                "part1" => days::day5::part1(&input).unwrap(),
-                "part2" => days::day5::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day6" => match args.part.as_str() {
-                "part1" => days::day6::part1(&input).unwrap(),
-                "part2" => days::day6::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day7" => match args.part.as_str() {
-                "part1" => days::day7::part1(&input).unwrap(),
-                "part2" => days::day7::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day8" => match args.part.as_str() {
-                "part1" => days::day8::part1(&input).unwrap(),
-                "part2" => days::day8::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day9" => match args.part.as_str() {
-                "part1" => days::day9::part1(&input).unwrap(),
-                "part2" => days::day9::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day10" => match args.part.as_str() {
-                "part1" => days::day10::part1(&input).unwrap(),
-                "part2" => days::day10::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day11" => match args.part.as_str() {
-                "part1" => days::day11::part1(&input).unwrap(),
-                "part2" => days::day11::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day12" => match args.part.as_str() {
-                "part1" => days::day12::part1(&input).unwrap(),
-                "part2" => days::day12::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day13" => match args.part.as_str() {
-                "part1" => days::day13::part1(&input).unwrap(),
-                "part2" => days::day13::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day14" => match args.part.as_str() {
-                "part1" => days::day14::part1(&input).unwrap(),
-                "part2" => days::day14::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day15" => match args.part.as_str() {
-                "part1" => days::day15::part1(&input).unwrap(),
-                "part2" => days::day15::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day16" => match args.part.as_str() {
-                "part1" => days::day16::part1(&input).unwrap(),
-                "part2" => days::day16::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day17" => match args.part.as_str() {
-                "part1" => days::day17::part1(&input).unwrap(),
-                "part2" => days::day17::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day18" => match args.part.as_str() {
-                "part1" => days::day18::part1(&input).unwrap(),
-                "part2" => days::day18::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day19" => match args.part.as_str() {
-                "part1" => days::day19::part1(&input).unwrap(),
-                "part2" => days::day19::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day20" => match args.part.as_str() {
-                "part1" => days::day20::part1(&input).unwrap(),
-                "part2" => days::day20::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day21" => match args.part.as_str() {
-                "part1" => days::day21::part1(&input).unwrap(),
-                "part2" => days::day21::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day22" => match args.part.as_str() {
-                "part1" => days::day22::part1(&input).unwrap(),
-                "part2" => days::day22::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day23" => match args.part.as_str() {
-                "part1" => days::day23::part1(&input).unwrap(),
-                "part2" => days::day23::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day24" => match args.part.as_str() {
-                "part1" => days::day24::part1(&input).unwrap(),
-                "part2" => days::day24::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            "day25" => match args.part.as_str() {
-                "part1" => days::day25::part1(&input).unwrap(),
-                "part2" => days::day25::part2(&input).unwrap(),
-                _ => format!("Invalid part {}", args.part),
-            },
-            _ => format!("Invalid day {}", args.day),
-        }
-    )
+    // Only --year and --plugin apply to the commands, the other options before
+    // a command would be ignored.
+    if args.command.is_some() && args.run != RunArgs::default() {
+        exit::fail(
+            exit::USAGE,
+            "only --year and --plugin can be given before a command",
+        );
+    }
+
+    for path in args.plugin.iter() {
+        if let Err(err) = plugin::load(path) {
+            exit::fail(exit::USAGE, err);
+        }
+    }
+
+    let year = args.year.unwrap_or_else(registry::latest_year);
+
+    match args.command {
+        Some(Command::New { year, day }) => {
+            if let Err(err) = scaffold::check_day(year, day) {
+                exit::fail(exit::USAGE, err);
+            }
+
+            match scaffold::new_day(Path::new("."), year, day) {
+                Ok(created) => {
+                    for path in created {
+                        println!("created {}", path.display());
+                    }
+                }
+                Err(err) => exit::fail(exit::FAILURE, err),
+            }
+        }
+        Some(Command::Watch {
+            day,
+            part,
+            interval,
+        }) => {
+            let Some(day) = registry::parse_day(&day) else {
+                exit::fail(exit::USAGE, format!("Invalid day {day}"));
+            };
+            let parts = match part {
+                Some(part) => match registry::parse_part(&part) {
+                    Some(part) => vec![part],
+                    None => exit::fail(exit::USAGE, format!("Invalid part {part}")),
+                },
+                None => vec![1, 2],
+            };
+
+            for &part in parts.iter() {
+                if registry::find(year, day, part).is_none() {
+                    exit::fail(
+                        exit::USAGE,
+                        format!("Invalid year {year}, day {day} or part {part}"),
+                    );
+                }
+            }
+
+            if let Err(err) = watch::watch(year, day, &parts, Duration::from_millis(interval)) {
+                exit::fail(exit::FAILURE, err);
+            }
+        }
+        Some(Command::Repl) => {
+            if let Err(err) = repl::run(year) {
+                exit::fail(exit::FAILURE, err);
+            }
+        }
+        Some(Command::Serve { port }) => {
+            let result = std::net::TcpListener::bind(("127.0.0.1", port))
+                .map_err(|err| format!("could not listen on port {port}: {err}"))
+                .and_then(|listener| {
+                    println!("listening on http://127.0.0.1:{port}");
+                    serve::serve(listener)
+                });
+
+            if let Err(err) = result {
+                exit::fail(exit::FAILURE, err);
+            }
+        }
+        Some(Command::Batch { timeout, dir }) => {
+            match batch::batch(&dir, year, Duration::from_secs(timeout)) {
+                Ok(out) => print!("{out}"),
+                Err(err) => exit::fail(exit::FAILURE, err),
+            }
+        }
+        Some(Command::Report {
+            days,
+            format,
+            redact,
+            timeout,
+            output,
+        }) => {
+            let days = match days {
+                Some(days) => match report::parse_days(&days) {
+                    Ok(days) => days,
+                    Err(err) => exit::fail(exit::USAGE, err),
+                },
+                None => registry::solvers()
+                    .iter()
+                    .filter(|s| s.year == year)
+                    .map(|s| s.day)
+                    .dedup()
+                    .collect(),
+            };
+
+            let report = report::report(&report::Options {
+                year,
+                days,
+                format,
+                redact,
+                timeout: Duration::from_secs(timeout),
+            });
+
+            match &output {
+                Some(path) => {
+                    if let Err(err) = report::write(path, &report) {
+                        exit::fail(exit::FAILURE, err);
+                    }
+                }
+                None => print!("{report}"),
+            }
+        }
+        Some(Command::Compare {
+            day,
+            part,
+            input,
+            timeout,
+        }) => {
+            let (Some(day), Some(part)) = (registry::parse_day(&day), registry::parse_part(&part))
+            else {
+                exit::fail(exit::USAGE, format!("Invalid day {day} or part {part}"));
+            };
+            let path = input.unwrap_or_else(|| registry::input_path(year, day));
+
+            let solvers = registry::implementations(year, day, part);
+            if solvers.is_empty() {
+                exit::fail(
+                    exit::USAGE,
+                    format!("Invalid year {year}, day {day} or part {part}"),
+                );
+            }
+
+            match std::fs::read_to_string(&path) {
+                Ok(input) => {
+                    let (out, agree) =
+                        compare::compare(&solvers, &input, Duration::from_secs(timeout));
+                    print!("{out}");
+                    if !agree {
+                        std::process::exit(exit::FAILURE);
+                    }
+                }
+                Err(err) => exit::fail(exit::INPUT_NOT_FOUND, format!("{}: {err}", path.display())),
+            }
+        }
+        Some(Command::Oracle { day, cases, seed }) => {
+            let day = match day.map(|day| registry::parse_day(&day).ok_or(day)) {
+                Some(Ok(day)) => Some(day),
+                Some(Err(day)) => exit::fail(exit::USAGE, format!("Invalid day {day}")),
+                None => None,
+            };
+
+            match oracle::check_all(year, day, cases, seed) {
+                Ok((out, ok)) => {
+                    print!("{out}");
+                    if !ok {
+                        std::process::exit(exit::FAILURE);
+                    }
+                }
+                Err(err) => exit::fail(exit::FAILURE, err),
+            }
+        }
+        Some(Command::Fuzz {
+            day,
+            cases,
+            seed,
+            timeout,
+        }) => {
+            let day = match day.map(|day| registry::parse_day(&day).ok_or(day)) {
+                Some(Ok(day)) => Some(day),
+                Some(Err(day)) => exit::fail(exit::USAGE, format!("Invalid day {day}")),
+                None => None,
+            };
+
+            match fuzz::fuzz_all(year, day, cases, seed, Duration::from_secs(timeout)) {
+                Ok((out, ok)) => {
+                    print!("{out}");
+                    if !ok {
+                        std::process::exit(exit::FAILURE);
+                    }
+                }
+                Err(err) => exit::fail(exit::FAILURE, err),
+            }
+        }
+        Some(Command::Generate { day, size, seed }) => {
+            let Some(day) = registry::parse_day(&day) else {
+                exit::fail(exit::USAGE, format!("Invalid day {day}"));
+            };
+
+            if registry::find(year, day, 1).is_none() {
+                exit::fail(exit::USAGE, format!("Invalid year {year} or day {day}"));
+            }
+            let Some(generate) = registry::generator(year, day) else {
+                exit::fail(exit::USAGE, format!("day{day} has no generator yet"));
+            };
+
+            print!("{}", generate(&mut StdRng::seed_from_u64(seed), size));
+        }
+        Some(Command::Scale {
+            day,
+            part,
+            implementation,
+            max_size,
+            seed,
+            timeout,
+        }) => {
+            let (Some(day), Some(part)) = (registry::parse_day(&day), registry::parse_part(&part))
+            else {
+                exit::fail(exit::USAGE, format!("Invalid day {day} or part {part}"));
+            };
+
+            let solver = match &implementation {
+                Some(name) => registry::find_named(year, day, part, name),
+                None => registry::find(year, day, part),
+            };
+            let Some(solver) = solver else {
+                exit::fail(
+                    exit::USAGE,
+                    format!("Invalid year {year}, day {day}, part {part} or implementation"),
+                );
+            };
+            let Some(generate) = registry::generator(year, day) else {
+                exit::fail(exit::USAGE, format!("day{day} has no generator yet"));
+            };
+
+            print!(
+                "{}",
+                scale::scale(&solver, generate, max_size, seed, Duration::from_secs(timeout))
+            );
+        }
+        Some(Command::Simulate {
+            day,
+            input,
+            steps,
+            dump,
+            cycle,
+        }) => {
+            let mut sim = load_simulation(year, &day, input);
+
+            match simulation::simulate(sim.as_mut(), steps, dump, cycle) {
+                Ok(out) => print!("{out}"),
+                Err(err) => exit::fail(exit::PARSE, err),
+            }
+        }
+        Some(Command::Animate {
+            day,
+            input,
+            fps,
+            steps,
+            paused,
+        }) => {
+            let mut sim = load_simulation(year, &day, input);
+            let controls = animate::stdin_controls();
+            let played = animate::play(
+                sim.as_mut(),
+                fps,
+                steps,
+                paused,
+                &controls,
+                &mut std::io::stdout(),
+            );
+            if let Err(err) = played {
+                exit::fail(exit::PARSE, err);
+            }
+        }
+        Some(Command::Detect { path }) => match std::fs::read_to_string(&path) {
+            Ok(input) => println!("{}", detect::describe(&detect::detect(year, &input))),
+            Err(err) => exit::fail(exit::INPUT_NOT_FOUND, format!("{}: {err}", path.display())),
+        },
+        None => run(&args.run, year),
+    }
+}
+
+fn run(args: &RunArgs, year: u32) {
+    let (Some(day), Some(part)) = (&args.day, &args.part) else {
+        unreachable!("clap requires the day and part");
+    };
+
+    let day = match day.as_str() {
+        "auto" => None,
+        day => match registry::parse_day(day) {
+            Some(day) => Some(day),
+            None => exit::fail(exit::USAGE, format!("Invalid day {day}")),
+        },
+    };
+    let Some(part) = registry::parse_part(part) else {
+        exit::fail(exit::USAGE, format!("Invalid part {part}"));
+    };
+
+    let render_format = args.render.as_ref().map(|path| {
+        render::Format::from_path(path).unwrap_or_else(|| {
+            exit::fail(
+                exit::USAGE,
+                format!("Invalid image file {}, expected a .ppm or .svg file", path.display()),
+            )
+        })
+    });
+
+    // Check the day exists before reading its input, so a typo is not
+    // reported as a missing file.
+    if let Some(day) = day {
+        find_solver(year, day, part, args.implementation.as_deref());
+    }
+
+    let path = match (&args.input, day) {
+        (Some(path), _) => path.clone(),
+        (None, Some(day)) => registry::input_path(year, day),
+        (None, None) => exit::fail(exit::USAGE, "An --input is required to detect the day"),
+    };
+
+    let input = match std::fs::read_to_string(&path) {
+        Ok(input) => input,
+        Err(err) => exit::fail(exit::INPUT_NOT_FOUND, format!("{}: {err}", path.display())),
+    };
+
+    // Running a day on the input of another day usually ends in a confusing
+    // panic, so check that the input looks right first.
+    let day = match day {
+        Some(day) => {
+            if let Some(warning) = detect::check(year, day, &input) {
+                if !args.quiet {
+                    eprintln!("warning: {warning}");
+                }
+            }
+            day
+        }
+        None => match detect::detect(year, &input).as_slice() {
+            [day] => {
+                if !args.quiet {
+                    eprintln!("detected day{day}");
+                }
+                *day
+            }
+            days => exit::fail(
+                exit::PARSE,
+                format!(
+                    "Could not detect the day, the input looks like {}",
+                    detect::describe(days)
+                ),
+            ),
+        },
+    };
+
+    let renderer = render_format.map(|format| match render::find(year, day) {
+        Some(renderer) => (renderer, format),
+        None => exit::fail(exit::USAGE, format!("Day {day} of {year} cannot be drawn")),
+    });
+
+    if args.checkpoint.is_some() && !checkpoint::supported(year, day, part) {
+        exit::fail(
+            exit::USAGE,
+            format!("day{day} part{part} does not save checkpoints"),
+        );
+    }
+
+    // The seed is printed before solving, so a run that crashes or hangs can
+    // be replayed too.
+    let seed = args.seed.unwrap_or_else(|| {
+        let seed = rng::random_seed();
+        if !args.quiet {
+            eprintln!("seed {seed}");
+        }
+        seed
+    });
+    let mut solver = rng::seeded(
+        &find_solver(year, day, part, args.implementation.as_deref()),
+        seed,
+    );
+    if let Some(path) = &args.checkpoint {
+        solver = checkpoint::wrap(&solver, checkpoint::Config::new(path.clone(), args.resume));
+    }
+    if solver.name != registry::DEFAULT_NAME && !args.quiet {
+        eprintln!("using the {} solver", solver.name);
+    }
+
+    // The bar is only drawn on a terminal, so it never ends up in a log.
+    let progress = Arc::new(progress::Progress::default());
+    let reporter = (args.explain.is_none() && !args.quiet && std::io::stderr().is_terminal())
+        .then(|| {
+            solver = progress::tracked(&solver, &progress);
+            progress::Reporter::start(&progress)
+        });
+
+    let answer = match (args.explain, args.timeout) {
+        (Some(format), _) => {
+            let (answer, records) = trace::capture(|| runner::run(&solver, &input).answer);
+            match format {
+                ExplainFormat::Table => println!("{}", trace::render_table(&records)),
+                ExplainFormat::Json => print!("{}", trace::render_json(&records)),
+            }
+            answer
+        }
+        (None, Some(timeout)) => {
+            runner::run_with_timeout(&solver, &input, Duration::from_secs(timeout)).answer
+        }
+        (None, None) => runner::run(&solver, &input).answer,
+    };
+    drop(reporter);
+
+    match answer {
+        Ok(answer) => println!("{answer}"),
+        Err(err) => exit::fail(exit::code(&err), err),
+    }
+
+    if let (Some((renderer, format)), Some(path)) = (renderer, &args.render) {
+        let image = renderer(&input, part).unwrap_or_else(|err| exit::fail(exit::PARSE, err));
+        if let Err(err) = render::write(&image, path, format) {
+            exit::fail(exit::FAILURE, err);
+        }
+    }
+}
+
+/// Parses the input of a day that can be simulated, exiting if it cannot.
+fn load_simulation(
+    year: u32,
+    day: &str,
+    input: Option<PathBuf>,
+) -> Box<dyn simulation::Simulation> {
+    let Some(day) = registry::parse_day(day) else {
+        exit::fail(exit::USAGE, format!("Invalid day {day}"));
+    };
+
+    let Some(parse) = simulation::find(year, day) else {
+        exit::fail(exit::USAGE, format!("Day {day} of {year} cannot be simulated"));
+    };
+
+    let path = input.unwrap_or_else(|| registry::input_path(year, day));
+    let input = match std::fs::read_to_string(&path) {
+        Ok(input) => input,
+        Err(err) => exit::fail(exit::INPUT_NOT_FOUND, format!("{}: {err}", path.display())),
+    };
+
+    parse(&input).unwrap_or_else(|err| exit::fail(exit::PARSE, err))
+}
+
+fn find_solver(year: u32, day: u32, part: u32, implementation: Option<&str>) -> registry::Solver {
+    let solver = match implementation {
+        Some(name) => registry::find_named(year, day, part, name),
+        None => registry::find(year, day, part),
+    };
+
+    solver.unwrap_or_else(|| match implementation {
+        Some(name) => exit::fail(
+            exit::USAGE,
+            format!(
+                "Invalid implementation {name} of day{day} part{part}, expected one of: {}",
+                registry::implementations(year, day, part)
+                    .iter()
+                    .map(|s| &s.name)
+                    .join(", ")
+            ),
+        ),
+        None => exit::fail(
+            exit::USAGE,
+            format!("Invalid year {year}, day {day} or part {part}"),
+        ),
+    })
+}
+
+#[test]
+fn test_global_args_before_command() {
+    let args =
+        CLI::try_parse_from(["aoc", "--plugin", "lib.so", "compare", "day1", "part1"]).unwrap();
+    assert_eq!(vec![PathBuf::from("lib.so")], args.plugin);
+    assert!(matches!(args.command, Some(Command::Compare { .. })));
+
+    let args = CLI::try_parse_from(["aoc", "--year", "2023", "compare", "day1", "part1"]).unwrap();
+    assert_eq!(Some(2023), args.year);
+    assert!(matches!(args.command, Some(Command::Compare { .. })));
+    assert_eq!(RunArgs::default(), args.run);
+
+    let args = CLI::try_parse_from(["aoc", "compare", "day1", "part1", "--year", "2023"]).unwrap();
+    assert_eq!(Some(2023), args.year);
+
+    let args = CLI::try_parse_from(["aoc", "--year", "2023", "7", "2"]).unwrap();
+    assert_eq!(Some(2023), args.year);
+    assert!(args.command.is_none());
+    assert_eq!(Some("7".to_string()), args.run.day);
+
+    let args =
+        CLI::try_parse_from(["aoc", "--input", "x.txt", "compare", "day1", "part1"]).unwrap();
+    assert_ne!(RunArgs::default(), args.run);
 }
@@ -0,0 +1,266 @@
+use std::fmt;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{
+    answer::Answer,
+    params::{self, Params},
+    registry::{self, Solver},
+    runner, y2023,
+};
+
+/// A slow but simple reference implementation of a part, which the other
+/// implementations of the part are checked against on generated inputs small
+/// enough for it.
+pub struct Oracle {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// The name of the reference implementation in the registry.
+    pub name: &'static str,
+    /// Generates an input that grows with the size.
+    pub generate: registry::Generate,
+    /// The params to solve a generated input with.
+    pub params: fn(&str) -> Params,
+}
+
+pub const ORACLES: &[Oracle] = &[
+    oracle(2023, 5, 2, "brute-force", y2023::day5::generate),
+    oracle(2023, 6, 2, "brute-force", y2023::day6::generate),
+    oracle(2023, 11, 1, "brute-force", y2023::day11::generate),
+    Oracle {
+        params: |_| param("expansion", 10),
+        ..oracle(2023, 11, 2, "brute-force", y2023::day11::generate)
+    },
+    oracle(2023, 18, 1, "flood-fill", y2023::day18::generate),
+    oracle(2023, 18, 2, "flood-fill", y2023::day18::generate),
+    oracle(2023, 19, 2, "brute-force", y2023::day19::generate),
+    Oracle {
+        // Part2 only takes its shortcut for steps that end in the middle of a
+        // side of the map after an even number of maps, like in the puzzle.
+        params: |input| {
+            let size = input.lines().count();
+            param("steps", size / 2 + 4 * size)
+        },
+        ..oracle(2023, 21, 2, "brute-force", y2023::day21::generate)
+    },
+];
+
+const fn oracle(
+    year: u32,
+    day: u32,
+    part: u32,
+    name: &'static str,
+    generate: registry::Generate,
+) -> Oracle {
+    Oracle {
+        year,
+        day,
+        part,
+        name,
+        generate,
+        params: |_| Params::new(),
+    }
+}
+
+fn param(name: &str, value: usize) -> Params {
+    Params::from([(name.to_string(), value.to_string())])
+}
+
+/// An implementation that disagrees with the oracle on an input.
+pub struct Failure {
+    pub implementation: String,
+    pub oracle: &'static str,
+    pub input: String,
+    pub params: Params,
+    pub expected: Answer,
+    pub actual: Result<Answer>,
+}
+
+/// Runs the other implementations and the oracle on `cases` generated inputs of
+/// growing sizes. Returns the first failure of each implementation, with the
+/// input shrunk to as few lines as still fail.
+pub fn check(oracle: &Oracle, cases: usize, seed: u64) -> Result<Vec<Failure>> {
+    let reference =
+        registry::find_named(oracle.year, oracle.day, oracle.part, oracle.name).ok_or(format!(
+            "day{} part{} has no implementation named {}",
+            oracle.day, oracle.part, oracle.name
+        ))?;
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut failures = Vec::new();
+
+    for solver in registry::implementations(oracle.year, oracle.day, oracle.part) {
+        if solver.name == oracle.name {
+            continue;
+        }
+
+        let fails = |input: &str| disagreement(oracle, &reference, &solver, input).is_some();
+
+        // The sizes grow slowly, so the first failure is on a small input.
+        let failing = (0..cases)
+            .map(|i| (oracle.generate)(&mut rng, 1 + i / 4))
+            .find(|input| fails(input));
+
+        if let Some(input) = failing {
+            let input = shrink(&input, fails);
+            let (expected, actual) = disagreement(oracle, &reference, &solver, &input).unwrap();
+
+            failures.push(Failure {
+                implementation: solver.name.clone(),
+                oracle: oracle.name,
+                params: (oracle.params)(&input),
+                input,
+                expected,
+                actual,
+            });
+        }
+    }
+
+    Ok(failures)
+}
+
+/// Checks the parts of the day, or of every day with an oracle, and describes
+/// the outcome of each. Returns false as well if any implementation failed.
+pub fn check_all(year: u32, day: Option<u32>, cases: usize, seed: u64) -> Result<(String, bool)> {
+    let oracles = ORACLES
+        .iter()
+        .filter(|o| o.year == year && day.is_none_or(|day| o.day == day))
+        .collect::<Vec<_>>();
+
+    if oracles.is_empty() {
+        return Err("no oracles for the day".to_string());
+    }
+
+    let mut out = String::new();
+    let mut ok = true;
+
+    for oracle in oracles {
+        let failures = check(oracle, cases, seed)?;
+        ok &= failures.is_empty();
+
+        if failures.is_empty() {
+            out.push_str(&format!(
+                "day{} part{}: all implementations agree with {} on {cases} inputs\n",
+                oracle.day, oracle.part, oracle.name
+            ));
+        }
+        for failure in failures {
+            out.push_str(&format!(
+                "day{} part{}: {failure}\n",
+                oracle.day, oracle.part
+            ));
+        }
+    }
+
+    Ok((out, ok))
+}
+
+/// Returns the answer of the oracle and the other answer if they disagree. An
+/// input the oracle fails on is not valid, so it never disagrees.
+fn disagreement(
+    oracle: &Oracle,
+    reference: &Solver,
+    solver: &Solver,
+    input: &str,
+) -> Option<(Answer, Result<Answer>)> {
+    params::with(&(oracle.params)(input), || {
+        let expected = runner::run(reference, input).answer.ok()?;
+        let actual = runner::run(solver, input)
+            .answer
+            .map_err(|err| err.to_string());
+
+        (actual.as_ref() != Ok(&expected)).then_some((expected, actual))
+    })
+}
+
+/// Removes lines from the input for as long as it keeps failing, starting with
+/// large chunks of lines.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let join = |lines: &[&str]| lines.iter().map(|l| format!("{l}\n")).collect::<String>();
+
+    let mut lines = input.lines().collect::<Vec<_>>();
+    let mut chunk = lines.len() / 2;
+
+    while chunk > 0 {
+        let mut removed = false;
+        let mut i = 0;
+
+        while i < lines.len() {
+            let candidate = [&lines[..i], &lines[(i + chunk).min(lines.len())..]].concat();
+
+            if fails(&join(&candidate)) {
+                lines = candidate;
+                removed = true;
+            } else {
+                i += chunk;
+            }
+        }
+
+        if !removed {
+            chunk /= 2;
+        }
+    }
+
+    join(&lines)
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let actual = match &self.actual {
+            Ok(answer) => answer.to_string(),
+            Err(err) => format!("an error: {err}"),
+        };
+
+        writeln!(
+            f,
+            "{} disagrees with {} on:",
+            self.implementation, self.oracle
+        )?;
+        for line in self.input.lines() {
+            writeln!(f, "  {line}")?;
+        }
+        for (name, value) in self.params.iter() {
+            writeln!(f, "with {name}={value}")?;
+        }
+        write!(f, "expected {}, got {actual}", self.expected)
+    }
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[test]
+fn test_shrink() {
+    let input = "1\n2\n3\n4\n5\n6\n7\n";
+
+    // Fails as long as 3 and 6 are both there.
+    assert_eq!(
+        "3\n6\n",
+        shrink(input, |s| s.contains("3\n") && s.contains("6\n"))
+    );
+    assert_eq!("", shrink(input, |_| true));
+}
+
+#[test]
+fn test_oracles() {
+    for oracle in ORACLES.iter() {
+        let failures = check(oracle, 100, 1).unwrap();
+
+        assert!(
+            failures.is_empty(),
+            "day{} part{}:\n{}",
+            oracle.day,
+            oracle.part,
+            failures
+                .iter()
+                .map(|f| f.to_string())
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use std::{cell::RefCell, collections::BTreeMap, str::FromStr};
+
+pub type Params = BTreeMap<String, String>;
+
+thread_local! {
+    static PARAMS: RefCell<Params> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Returns the value of a tunable constant, such as the number of steps to
+/// walk, or `default` if it was not overridden.
+///
+/// Example:
+///    let steps = params::get("steps", 64)?;
+pub fn get<T: FromStr>(name: &str, default: T) -> Result<T> {
+    PARAMS.with(|params| match params.borrow().get(name) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("invalid value for param {name}: '{value}'")),
+        None => Ok(default),
+    })
+}
+
+/// Runs `f` with the given params overriding the defaults.
+pub fn with<T>(params: &Params, f: impl FnOnce() -> T) -> T {
+    let previous = PARAMS.with(|p| p.replace(params.clone()));
+    let result = f();
+    PARAMS.with(|p| p.replace(previous));
+
+    result
+}
+
+/// Parses a param given as "name=value".
+pub fn parse(s: &str) -> Result<(String, String)> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or(format!("expected 'name=value', got '{s}'"))?;
+
+    let (name, value) = (name.trim(), value.trim());
+    if name.is_empty() || value.is_empty() {
+        return Err(format!("expected 'name=value', got '{s}'"));
+    }
+
+    Ok((name.to_string(), value.to_string()))
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[test]
+fn test_get() {
+    assert_eq!(Ok(64), get("steps", 64));
+
+    let params = Params::from([("steps".to_string(), "6".to_string())]);
+    assert_eq!(Ok(6), with(&params, || get("steps", 64)));
+    assert_eq!(Ok(64), get("steps", 64));
+
+    let params = Params::from([("steps".to_string(), "six".to_string())]);
+    assert!(with(&params, || get("steps", 64)).is_err());
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        Ok(("steps".to_string(), "100".to_string())),
+        parse("steps = 100")
+    );
+    assert!(parse("steps").is_err());
+    assert!(parse("=100").is_err());
+}
@@ -0,0 +1,194 @@
+use std::{
+    ffi::{c_char, CStr},
+    path::Path,
+    ptr,
+    sync::Arc,
+};
+
+use libloading::Library;
+
+use crate::{
+    answer::{Answer, Unsolved},
+    registry::{self, Solver},
+};
+
+/// Changes whenever the types below change, plugins built against another
+/// version are refused.
+pub const ABI_VERSION: u32 = 1;
+
+/// The symbol of the `Entry` function every plugin exports.
+const ENTRY_SYMBOL: &[u8] = b"aoc_plugin\0";
+
+/// Returns the table of solvers of the plugin. It is called once, and the
+/// table must stay valid while the plugin is loaded.
+pub type Entry = unsafe extern "C" fn() -> *const Table;
+
+/// Solves the `len` bytes of UTF-8 input at `input`, and returns 0 on success.
+/// Either way a NUL terminated answer or error message is stored in `answer`,
+/// which is handed back to the `free` of the table once it has been copied.
+///
+/// Unwinding out of a plugin aborts the process, so panics have to be caught
+/// and returned as errors.
+pub type Solve =
+    unsafe extern "C" fn(input: *const u8, len: usize, answer: *mut *mut c_char) -> i32;
+
+#[repr(C)]
+pub struct Table {
+    pub abi_version: u32,
+    pub solvers: *const PluginSolver,
+    pub count: usize,
+    pub free: unsafe extern "C" fn(answer: *mut c_char),
+}
+
+#[repr(C)]
+pub struct PluginSolver {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// A NUL terminated name that tells it apart from the built-in solver.
+    pub name: *const c_char,
+    pub solve: Solve,
+}
+
+/// Loads the shared library at `path` and registers its solvers, which take
+/// precedence over the built-in ones. The library stays loaded as long as its
+/// solvers are registered, so in practice until the process exits.
+pub fn load(path: &Path) -> Result<Vec<Solver>> {
+    // Loading a library runs its initializers, plugins are trusted as much as
+    // the rest of the code. The errors of the loader already name the library.
+    let library = unsafe { Library::new(path) }.map_err(|err| err.to_string())?;
+    let entry = *unsafe { library.get::<Entry>(ENTRY_SYMBOL) }.map_err(|err| err.to_string())?;
+
+    let solvers = unsafe { solvers(entry(), Some(Arc::new(library))) }
+        .map_err(|err| format!("{}: {err}", path.display()))?;
+    for solver in solvers.iter() {
+        registry::register(solver.clone());
+    }
+
+    Ok(solvers)
+}
+
+/// Wraps the solvers of the table. Each of them holds on to the library, so it
+/// is not unloaded while they can still be called.
+unsafe fn solvers(table: *const Table, library: Option<Arc<Library>>) -> Result<Vec<Solver>> {
+    let Some(table) = table.as_ref() else {
+        return Err("the plugin returned no table of solvers".to_string());
+    };
+
+    if table.abi_version != ABI_VERSION {
+        return Err(format!(
+            "the plugin was built for ABI version {}, expected {ABI_VERSION}",
+            table.abi_version
+        ));
+    }
+
+    if table.count == 0 {
+        return Ok(Vec::new());
+    }
+    if table.solvers.is_null() {
+        return Err("the plugin returned no solvers".to_string());
+    }
+
+    std::slice::from_raw_parts(table.solvers, table.count)
+        .iter()
+        .map(|s| {
+            if s.name.is_null() {
+                return Err(format!(
+                    "the solver of day{} part{} has no name",
+                    s.day, s.part
+                ));
+            }
+
+            let (solve, free, library) = (s.solve, table.free, library.clone());
+
+            Ok(Solver {
+                year: s.year,
+                day: s.day,
+                part: s.part,
+                name: CStr::from_ptr(s.name).to_string_lossy().into_owned(),
+                solve: Arc::new(move |input| {
+                    let _library = &library;
+                    unsafe { call(solve, free, input).map_err(Unsolved::Failed) }
+                }),
+            })
+        })
+        .collect()
+}
+
+unsafe fn call(
+    solve: Solve,
+    free: unsafe extern "C" fn(*mut c_char),
+    input: &str,
+) -> Result<Answer> {
+    let mut answer = ptr::null_mut();
+    let status = solve(input.as_ptr(), input.len(), &mut answer);
+
+    if answer.is_null() {
+        return Err(format!("the plugin returned no answer (status {status})"));
+    }
+
+    let text = CStr::from_ptr(answer).to_string_lossy().into_owned();
+    free(answer);
+
+    if status == 0 {
+        Ok(Answer::parse(&text))
+    } else {
+        Err(text)
+    }
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[cfg(test)]
+unsafe extern "C" fn test_solve(input: *const u8, len: usize, answer: *mut *mut c_char) -> i32 {
+    let input = std::str::from_utf8(std::slice::from_raw_parts(input, len)).unwrap();
+
+    let (status, text) = match input.lines().count() {
+        0 => (1, "empty input".to_string()),
+        lines => (0, lines.to_string()),
+    };
+    *answer = std::ffi::CString::new(text).unwrap().into_raw();
+
+    status
+}
+
+#[cfg(test)]
+unsafe extern "C" fn test_free(answer: *mut c_char) {
+    drop(std::ffi::CString::from_raw(answer));
+}
+
+#[test]
+fn test_solvers() {
+    let entries = [PluginSolver {
+        year: 2023,
+        day: 1,
+        part: 1,
+        name: c"lines".as_ptr(),
+        solve: test_solve,
+    }];
+    let mut table = Table {
+        abi_version: ABI_VERSION,
+        solvers: entries.as_ptr(),
+        count: entries.len(),
+        free: test_free,
+    };
+
+    let loaded = unsafe { solvers(&table, None) }.unwrap();
+    assert_eq!(1, loaded.len());
+    assert_eq!(
+        (2023, 1, 1),
+        (loaded[0].year, loaded[0].day, loaded[0].part)
+    );
+    assert_eq!("lines", loaded[0].name);
+    assert_eq!(Ok(Answer::Int(2)), (loaded[0].solve)("a\nb\n"));
+    assert_eq!(Err("empty input".into()), (loaded[0].solve)(""));
+
+    table.abi_version = ABI_VERSION + 1;
+    assert!(unsafe { solvers(&table, None) }.is_err());
+    assert!(unsafe { solvers(ptr::null(), None) }.is_err());
+}
@@ -0,0 +1,193 @@
+use std::{
+    cell::RefCell,
+    io::Write,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, RecvTimeoutError, Sender},
+        Arc,
+    },
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+use crate::{registry::Solver, runner::format_duration};
+
+/// How often the bar is drawn.
+const REFRESH: Duration = Duration::from_millis(100);
+
+/// Quick solvers are done before the bar would show up.
+const DELAY: Duration = Duration::from_millis(500);
+
+const WIDTH: usize = 30;
+
+/// How far a solver has come, e.g. in button presses or branches searched.
+#[derive(Debug, Default)]
+pub struct Progress {
+    /// Zero while the total is not known.
+    total: AtomicU64,
+    done: AtomicU64,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<Progress>>> = const { RefCell::new(None) };
+}
+
+/// Sets how many steps the solver will take. Does nothing unless the solver
+/// runs with a progress bar, as do the other functions.
+///
+/// Example:
+///    progress::set_total(entries.len());
+pub fn set_total(total: usize) {
+    CURRENT.with(|current| {
+        if let Some(progress) = current.borrow().as_ref() {
+            progress.total.store(total as u64, Ordering::Relaxed);
+        }
+    });
+}
+
+/// Counts a step of the solver.
+pub fn inc() {
+    CURRENT.with(|current| {
+        if let Some(progress) = current.borrow().as_ref() {
+            progress.done.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+}
+
+/// Runs `f` reporting into `progress`.
+pub fn with<T>(progress: &Arc<Progress>, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.with(|c| c.replace(Some(progress.clone())));
+    let result = f();
+    CURRENT.with(|c| c.replace(previous));
+
+    result
+}
+
+/// Wraps a solver so it reports into `progress`, also when it is run on
+/// another thread.
+pub fn tracked(solver: &Solver, progress: &Arc<Progress>) -> Solver {
+    let solve = solver.solve.clone();
+    let progress = progress.clone();
+
+    Solver {
+        solve: Arc::new(move |input| with(&progress, || solve(input))),
+        ..solver.clone()
+    }
+}
+
+/// Draws the progress as a line, with the time left estimated from the time
+/// taken so far.
+pub fn bar(progress: &Progress, elapsed: Duration) -> String {
+    let total = progress.total.load(Ordering::Relaxed);
+    let done = progress.done.load(Ordering::Relaxed);
+    let taken = format_duration(elapsed);
+
+    if total == 0 {
+        return format!("{done} done, {taken}");
+    }
+
+    let fraction = (done as f64 / total as f64).min(1.0);
+    let filled = (fraction * WIDTH as f64) as usize;
+    let eta = match done {
+        0 => "?".to_string(),
+        _ => format_duration(elapsed.mul_f64((1.0 - fraction) / fraction)),
+    };
+
+    format!(
+        "[{}{}] {done}/{total} {:.0}% {taken}, ETA {eta}",
+        "#".repeat(filled),
+        ".".repeat(WIDTH - filled),
+        fraction * 100.0
+    )
+}
+
+/// Draws the bar on stderr until it is dropped.
+pub struct Reporter {
+    stop: Sender<()>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Reporter {
+    pub fn start(progress: &Arc<Progress>) -> Reporter {
+        let (stop, stopped) = mpsc::channel();
+        let progress = progress.clone();
+        let start = Instant::now();
+
+        let thread = std::thread::spawn(move || {
+            let mut drawn = false;
+
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(REFRESH) {
+                let reported = progress.total.load(Ordering::Relaxed) > 0
+                    || progress.done.load(Ordering::Relaxed) > 0;
+                if start.elapsed() < DELAY || !reported {
+                    continue;
+                }
+
+                eprint!("\r\x1b[2K{}", bar(&progress, start.elapsed()));
+                let _ = std::io::stderr().flush();
+                drawn = true;
+            }
+
+            if drawn {
+                eprint!("\r\x1b[2K");
+            }
+        });
+
+        Reporter {
+            stop,
+            thread: Some(thread),
+        }
+    }
+}
+
+impl Drop for Reporter {
+    /// Clears the bar, so the answer is not printed after it.
+    fn drop(&mut self) {
+        let _ = self.stop.send(());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[test]
+fn test_bar() {
+    let progress = Progress::default();
+    assert_eq!("0 done, 2.00s", bar(&progress, Duration::from_secs(2)));
+
+    let progress = Arc::new(Progress::default());
+    with(&progress, || {
+        set_total(4);
+        inc();
+    });
+    inc();
+    assert_eq!(
+        "[#######.......................] 1/4 25% 2.00s, ETA 6.00s",
+        bar(&progress, Duration::from_secs(2))
+    );
+}
+
+#[test]
+fn test_tracked() {
+    let solver = Solver {
+        year: 2023,
+        day: 1,
+        part: 1,
+        name: "counting".to_string(),
+        solve: Arc::new(|_| {
+            set_total(3);
+            (0..3).for_each(|_| inc());
+            Ok(3usize.into())
+        }),
+    };
+
+    let progress = Arc::new(Progress::default());
+    let solver = tracked(&solver, &progress);
+    std::thread::spawn(move || (solver.solve)(""))
+        .join()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(3, progress.done.load(Ordering::Relaxed));
+    assert_eq!(3, progress.total.load(Ordering::Relaxed));
+}
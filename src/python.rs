@@ -0,0 +1,153 @@
+use pyo3::{
+    exceptions::{PyOverflowError, PyValueError},
+    prelude::*,
+    types::{PyDict, PyInt},
+};
+
+use crate::{
+    answer::Answer,
+    registry, runner,
+    y2023::{day12, day19, day5},
+};
+
+/// A range as a tuple of its start and end.
+type Range = (u64, u64);
+
+/// Solves a part of a day of the latest year, or of `year`.
+///
+/// Example:
+///    aoc.solve(5, 2, open("inputs/2023/day5.txt").read())
+#[pyfunction]
+#[pyo3(signature = (day, part, input, year = None))]
+fn solve(
+    py: Python<'_>,
+    day: u32,
+    part: u32,
+    input: &str,
+    year: Option<u32>,
+) -> PyResult<Py<PyAny>> {
+    let year = year.unwrap_or_else(registry::latest_year);
+    let solver = registry::find(year, day, part)
+        .ok_or_else(|| invalid(format!("Invalid year {year}, day {day} or part {part}")))?;
+
+    // Other Python threads can run while the solver does.
+    let answer = py
+        .detach(|| runner::run(&solver, input).answer)
+        .map_err(|err| invalid(err.to_string()))?;
+
+    match answer {
+        Answer::Int(n) => Ok(n.into_pyobject(py)?.into_any().unbind()),
+        Answer::Big(n) => Ok(py.get_type::<PyInt>().call1((n.to_string(),))?.unbind()),
+        Answer::Text(s) => Ok(s.into_pyobject(py)?.into_any().unbind()),
+    }
+}
+
+fn invalid(err: String) -> PyErr {
+    PyValueError::new_err(err)
+}
+
+/// The almanac of day5.
+#[pyclass(name = "Almanac", module = "aoc.day5")]
+struct Almanac(day5::Almanac);
+
+#[pymethods]
+impl Almanac {
+    #[getter]
+    fn seeds(&self) -> Vec<u64> {
+        self.0.seeds.clone()
+    }
+
+    /// The ranges of seeds of part 2, each with the end excluded.
+    #[getter]
+    fn seed_ranges(&self) -> Vec<Range> {
+        self.0
+            .seed_ranges
+            .iter()
+            .map(|r| (r.start, r.end))
+            .collect()
+    }
+
+    fn location(&self, seed: u64) -> u64 {
+        self.0.location(seed)
+    }
+
+    /// Resolves ranges of seeds to the ranges of their locations, each with
+    /// the end excluded.
+    fn resolve_ranges(&self, seeds: Vec<Range>) -> Vec<Range> {
+        let seeds = seeds.into_iter().map(|(start, end)| start..end).collect();
+
+        self.0
+            .resolve_ranges(seeds)
+            .into_iter()
+            .map(|r| (r.start, r.end))
+            .collect()
+    }
+}
+
+#[pyfunction(name = "parse")]
+fn day5_parse(input: &str) -> PyResult<Almanac> {
+    day5::parse(input).map(Almanac).map_err(invalid)
+}
+
+/// Returns the rows of springs of day12, each with the sizes of its groups of
+/// damaged springs.
+#[pyfunction(name = "parse")]
+fn day12_parse(input: &str) -> PyResult<Vec<(String, Vec<usize>)>> {
+    day12::parse(input).map_err(invalid)
+}
+
+/// Counts the arrangements of damaged springs that match the groups.
+#[pyfunction]
+fn count_alternatives(springs: &str, groups: Vec<usize>) -> PyResult<usize> {
+    if !day12::is_springs(springs) {
+        return Err(invalid(format!("invalid springs: {springs}")));
+    }
+
+    day12::count_alternatives(springs, &groups, &mut Default::default())
+        .ok_or_else(|| PyOverflowError::new_err("there are too many arrangements to count"))
+}
+
+/// Returns the ranges of ratings that the workflows of day19 accept, as a
+/// dict from each category to its range with the end included.
+#[pyfunction]
+fn accepted_ranges(py: Python<'_>, input: &str) -> PyResult<Vec<Py<PyDict>>> {
+    let accepted = day19::accepted_ranges(input).map_err(invalid)?;
+
+    accepted
+        .into_iter()
+        .map(|ranges| {
+            let dict = PyDict::new(py);
+            for (category, range) in [
+                ("x", ranges.x),
+                ("m", ranges.m),
+                ("a", ranges.a),
+                ("s", ranges.s),
+            ] {
+                dict.set_item(category, (*range.start(), *range.end()))?;
+            }
+            Ok(dict.unbind())
+        })
+        .collect()
+}
+
+#[pymodule]
+fn aoc(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+
+    let day5 = PyModule::new(py, "day5")?;
+    day5.add_class::<Almanac>()?;
+    day5.add_function(wrap_pyfunction!(day5_parse, &day5)?)?;
+    m.add_submodule(&day5)?;
+
+    let day12 = PyModule::new(py, "day12")?;
+    day12.add_function(wrap_pyfunction!(day12_parse, &day12)?)?;
+    day12.add_function(wrap_pyfunction!(count_alternatives, &day12)?)?;
+    m.add_submodule(&day12)?;
+
+    let day19 = PyModule::new(py, "day19")?;
+    day19.add_function(wrap_pyfunction!(accepted_ranges, &day19)?)?;
+    m.add_submodule(&day19)?;
+
+    Ok(())
+}
@@ -0,0 +1,300 @@
+use std::{
+    path::PathBuf,
+    sync::{Arc, RwLock},
+};
+
+use rand::rngs::StdRng;
+
+use crate::answer::Solution;
+
+pub type Solve = Arc<dyn Fn(&str) -> Solution + Send + Sync>;
+
+/// The signature of the `part1` and `part2` functions of the days.
+type PartFn = fn(&str) -> Solution;
+
+/// Generates a random input of the day, larger inputs for larger sizes.
+pub type Generate = fn(&mut StdRng, usize) -> String;
+
+/// The puzzle inputs are stored in `inputs/<year>/dayN.txt`.
+pub const INPUTS_DIR: &str = "./inputs";
+
+/// The name of the implementation of the built-in solvers.
+pub const DEFAULT_NAME: &str = "default";
+
+#[derive(Clone)]
+pub struct Solver {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// Tells implementations of the same part apart, e.g. a plugin from the
+    /// built-in solver.
+    pub name: String,
+    pub solve: Solve,
+}
+
+/// Solvers registered at runtime, e.g. from plugins.
+static REGISTERED: RwLock<Vec<Solver>> = RwLock::new(Vec::new());
+
+/// Registers part1 and part2 of each listed day module, grouped by the module
+/// of the year they belong to.
+macro_rules! register {
+    ( $( $year:literal => $year_module:ident { $( $day:literal => $module:ident ),* $(,)? } )* ) => {
+        fn builtin() -> Vec<Solver> {
+            let solver = |year, day, part, solve: PartFn| Solver {
+                year,
+                day,
+                part,
+                name: DEFAULT_NAME.to_string(),
+                solve: Arc::new(solve),
+            };
+
+            let mut solvers = vec![
+                $($(
+                    solver($year, $day, 1, crate::$year_module::$module::part1),
+                    solver($year, $day, 2, crate::$year_module::$module::part2),
+                )*)*
+            ];
+
+            solvers.extend(IMPLEMENTATIONS.iter().map(|&(year, day, part, name, solve)| Solver {
+                name: name.to_string(),
+                ..solver(year, day, part, solve)
+            }));
+
+            solvers
+        }
+    };
+}
+
+register! {
+    2023 => y2023 {
+        1 => day1,
+        2 => day2,
+        3 => day3,
+        4 => day4,
+        5 => day5,
+        6 => day6,
+        7 => day7,
+        8 => day8,
+        9 => day9,
+        10 => day10,
+        11 => day11,
+        12 => day12,
+        13 => day13,
+        14 => day14,
+        15 => day15,
+        16 => day16,
+        17 => day17,
+        18 => day18,
+        19 => day19,
+        20 => day20,
+        21 => day21,
+        22 => day22,
+        23 => day23,
+        24 => day24,
+        25 => day25,
+    }
+}
+
+/// Other built-in implementations of a part next to the default one, selected
+/// by name with `--impl`.
+const IMPLEMENTATIONS: &[(u32, u32, u32, &str, PartFn)] = &[
+    (2023, 5, 2, "brute-force", crate::y2023::day5::part2_brute_force),
+    (2023, 6, 2, "brute-force", crate::y2023::day6::part2_brute_force),
+    (2023, 6, 2, "closed-form", crate::y2023::day6::part2_closed_form),
+    (2023, 11, 1, "brute-force", crate::y2023::day11::part1_brute_force),
+    (2023, 11, 2, "brute-force", crate::y2023::day11::part2_brute_force),
+    (2023, 18, 1, "flood-fill", crate::y2023::day18::part1_flood_fill),
+    (2023, 18, 2, "flood-fill", crate::y2023::day18::part2_flood_fill),
+    (2023, 19, 2, "brute-force", crate::y2023::day19::part2_brute_force),
+    (2023, 21, 2, "brute-force", crate::y2023::day21::part2_brute_force),
+];
+
+/// The days that can generate random inputs. New days have none until one is
+/// written and listed here.
+const GENERATORS: &[(u32, u32, Generate)] = &[
+    (2023, 1, crate::y2023::day1::generate),
+    (2023, 2, crate::y2023::day2::generate),
+    (2023, 3, crate::y2023::day3::generate),
+    (2023, 4, crate::y2023::day4::generate),
+    (2023, 5, crate::y2023::day5::generate),
+    (2023, 6, crate::y2023::day6::generate),
+    (2023, 7, crate::y2023::day7::generate),
+    (2023, 8, crate::y2023::day8::generate),
+    (2023, 9, crate::y2023::day9::generate),
+    (2023, 10, crate::y2023::day10::generate),
+    (2023, 11, crate::y2023::day11::generate),
+    (2023, 12, crate::y2023::day12::generate),
+    (2023, 13, crate::y2023::day13::generate),
+    (2023, 14, crate::y2023::day14::generate),
+    (2023, 15, crate::y2023::day15::generate),
+    (2023, 16, crate::y2023::day16::generate),
+    (2023, 17, crate::y2023::day17::generate),
+    (2023, 18, crate::y2023::day18::generate),
+    (2023, 19, crate::y2023::day19::generate),
+    (2023, 20, crate::y2023::day20::generate),
+    (2023, 21, crate::y2023::day21::generate),
+    (2023, 22, crate::y2023::day22::generate),
+    (2023, 23, crate::y2023::day23::generate),
+    (2023, 24, crate::y2023::day24::generate),
+    (2023, 25, crate::y2023::day25::generate),
+];
+
+/// Returns the input generator of the day.
+pub fn generator(year: u32, day: u32) -> Option<Generate> {
+    GENERATORS
+        .iter()
+        .find(|(y, d, _)| *y == year && *d == day)
+        .map(|&(_, _, generate)| generate)
+}
+
+/// Returns the solvers sorted by year, day and part. The built-in solvers of a
+/// part come before the ones registered at runtime.
+pub fn solvers() -> Vec<Solver> {
+    let mut solvers = builtin();
+    solvers.extend(REGISTERED.read().unwrap().iter().cloned());
+    solvers.sort_by_key(|s| (s.year, s.day, s.part));
+    solvers
+}
+
+/// Adds a solver to the registry. It takes precedence over the solvers that
+/// were already registered for the same part.
+pub fn register(solver: Solver) {
+    REGISTERED.write().unwrap().push(solver);
+}
+
+/// Returns the solver used for the part: the last one registered at runtime,
+/// or else the default built-in one.
+pub fn find(year: u32, day: u32, part: u32) -> Option<Solver> {
+    let matches = |s: &Solver| s.year == year && s.day == day && s.part == part;
+
+    let registered = REGISTERED.read().unwrap().iter().rev().find(|s| matches(s)).cloned();
+
+    registered.or_else(|| {
+        builtin()
+            .into_iter()
+            .find(|s| matches(s) && s.name == DEFAULT_NAME)
+    })
+}
+
+/// Returns the implementation of the part with the given name. A solver
+/// registered at runtime hides a built-in one with the same name.
+pub fn find_named(year: u32, day: u32, part: u32, name: &str) -> Option<Solver> {
+    implementations(year, day, part)
+        .into_iter()
+        .rev()
+        .find(|s| s.name == name)
+}
+
+/// Returns every implementation of the part, the built-in ones first.
+pub fn implementations(year: u32, day: u32, part: u32) -> Vec<Solver> {
+    solvers()
+        .into_iter()
+        .filter(|s| s.year == year && s.day == day && s.part == part)
+        .collect()
+}
+
+/// Returns the most recent year with registered solvers, which is the one used
+/// unless another year is asked for.
+pub fn latest_year() -> u32 {
+    solvers().iter().map(|s| s.year).max().unwrap()
+}
+
+/// Day 25 only has one puzzle, its second star is given for all the others.
+/// Its part2 is still registered so every day has both parts.
+pub fn has_puzzle(day: u32, part: u32) -> bool {
+    part == 1 || day != 25
+}
+
+pub fn input_path(year: u32, day: u32) -> PathBuf {
+    PathBuf::from(INPUTS_DIR)
+        .join(year.to_string())
+        .join(format!("day{day}.txt"))
+}
+
+/// The known answers for an input are kept next to it in
+/// `inputs/<year>/dayN.expected`, in the same format as the examples.
+pub fn answers_path(year: u32, day: u32) -> PathBuf {
+    input_path(year, day).with_extension("expected")
+}
+
+/// Parses a day given either as "day5" or "5".
+pub fn parse_day(s: &str) -> Option<u32> {
+    s.strip_prefix("day").unwrap_or(s).parse().ok()
+}
+
+/// Parses a part given either as "part2" or "2".
+pub fn parse_part(s: &str) -> Option<u32> {
+    s.strip_prefix("part").unwrap_or(s).parse().ok()
+}
+
+#[test]
+fn test_find() {
+    assert!(find(2023, 1, 1).is_some());
+    assert!(find(2023, 25, 2).is_some());
+    assert!(find(2023, 26, 1).is_none());
+    assert!(find(2023, 1, 3).is_none());
+    assert!(find(2015, 1, 1).is_none());
+}
+
+#[test]
+fn test_has_puzzle() {
+    assert!(has_puzzle(24, 2));
+    assert!(has_puzzle(25, 1));
+    assert!(!has_puzzle(25, 2));
+}
+
+#[test]
+fn test_implementations() {
+    assert_eq!(DEFAULT_NAME, find(2023, 5, 2).unwrap().name);
+    assert!(find_named(2023, 5, 2, "brute-force").is_some());
+    assert!(find_named(2023, 5, 2, "nope").is_none());
+
+    let names = implementations(2023, 6, 2)
+        .into_iter()
+        .map(|s| s.name)
+        .collect::<Vec<_>>();
+    assert_eq!(vec![DEFAULT_NAME, "brute-force", "closed-form"], names);
+}
+
+#[test]
+fn test_generator() {
+    assert!(generator(2023, 1).is_some());
+    assert!(generator(2023, 26).is_none());
+}
+
+#[test]
+fn test_generated_inputs() {
+    use rand::SeedableRng;
+    use std::time::Duration;
+
+    for day in 1..=25 {
+        let generate = generator(2023, day).unwrap();
+
+        for (size, seed) in [(1, 1), (3, 2), (6, 3)] {
+            let input = generate(&mut StdRng::seed_from_u64(seed), size);
+            assert!(
+                crate::detect::detect(2023, &input).contains(&day),
+                "day{day} size {size}:\n{input}"
+            );
+
+            for part in (1..=2).filter(|&part| has_puzzle(day, part)) {
+                let solver = find(2023, day, part).unwrap();
+                let outcome =
+                    crate::runner::run_with_timeout(&solver, &input, Duration::from_secs(10));
+                assert!(
+                    outcome.answer.is_ok(),
+                    "day{day} part{part} size {size}: {:?}\n{input}",
+                    outcome.answer
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(Some(5), parse_day("day5"));
+    assert_eq!(Some(5), parse_day("5"));
+    assert_eq!(None, parse_day("dayfive"));
+    assert_eq!(Some(2), parse_part("part2"));
+}
@@ -0,0 +1,221 @@
+use std::path::Path;
+
+use crate::y2023;
+
+/// The size of a tile in pixels.
+const CELL: usize = 8;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const WHITE: Color = Color(255, 255, 255);
+    pub const GRAY: Color = Color(128, 128, 128);
+    pub const DARK: Color = Color(40, 44, 52);
+    pub const RED: Color = Color(220, 50, 47);
+    pub const GREEN: Color = Color(80, 180, 90);
+    pub const BLUE: Color = Color(38, 139, 210);
+    pub const YELLOW: Color = Color(250, 200, 40);
+
+    /// Reads a color written as #rrggbb, e.g. in the dig plan of day18.
+    pub fn parse_hex(s: &str) -> Option<Color> {
+        let hex = s.strip_prefix('#')?;
+        if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some(Color(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    /// Blends from this color at 0 to `other` at 1, e.g. for a heat map.
+    pub fn mix(self, other: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+        Color(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+
+    fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// A picture of a puzzle as a grid of colored tiles, `width` tiles to the
+/// right and `height` tiles down.
+pub struct Image {
+    width: usize,
+    height: usize,
+    tiles: Vec<Color>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Color) -> Image {
+        Image {
+            width,
+            height,
+            tiles: vec![background; width * height],
+        }
+    }
+
+    /// Colors the tile in column `x` of row `y`. Tiles outside the image are
+    /// ignored.
+    pub fn set(&mut self, x: usize, y: usize, color: Color) {
+        if x < self.width && y < self.height {
+            self.tiles[y * self.width + x] = color;
+        }
+    }
+
+    /// A binary PPM file with each tile as a square of pixels.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let (width, height) = (self.width * CELL, self.height * CELL);
+        let mut out = format!("P6\n{width} {height}\n255\n").into_bytes();
+
+        for row in self.tiles.chunks(self.width.max(1)) {
+            for _ in 0..CELL {
+                for color in row {
+                    for _ in 0..CELL {
+                        out.extend([color.0, color.1, color.2]);
+                    }
+                }
+            }
+        }
+
+        out
+    }
+
+    /// An SVG file with a rectangle for each run of tiles of the same color in
+    /// a row.
+    pub fn to_svg(&self) -> String {
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+            self.width * CELL,
+            self.height * CELL,
+            self.width,
+            self.height
+        );
+
+        for (y, row) in self.tiles.chunks(self.width.max(1)).enumerate() {
+            let mut x = 0;
+            for (color, run) in row.chunk_by(|a, b| a == b).map(|run| (run[0], run.len())) {
+                out.push_str(&format!(
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{run}\" height=\"1\" fill=\"{}\"/>\n",
+                    color.hex()
+                ));
+                x += run;
+            }
+        }
+
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Format {
+    Ppm,
+    Svg,
+}
+
+impl Format {
+    /// Picks the format from the extension of the file.
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "ppm" => Some(Format::Ppm),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// Draws the state of a part after solving it.
+pub type Render = fn(&str, u32) -> Result<Image>;
+
+/// The days that can be drawn.
+const RENDERERS: &[(u32, u32, Render)] = &[
+    (2023, 10, y2023::day10::render),
+    (2023, 14, y2023::day14::render),
+    (2023, 16, y2023::day16::render),
+    (2023, 17, y2023::day17::render),
+    (2023, 18, y2023::day18::render),
+    (2023, 21, y2023::day21::render),
+    (2023, 23, y2023::day23::render),
+];
+
+/// Returns the renderer of the day.
+pub fn find(year: u32, day: u32) -> Option<Render> {
+    RENDERERS
+        .iter()
+        .find(|(y, d, _)| *y == year && *d == day)
+        .map(|&(_, _, render)| render)
+}
+
+/// Writes the image to the file in its format.
+pub fn write(image: &Image, path: &Path, format: Format) -> Result<()> {
+    let bytes = match format {
+        Format::Ppm => image.to_ppm(),
+        Format::Svg => image.to_svg().into_bytes(),
+    };
+
+    std::fs::write(path, bytes).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[cfg(test)]
+const BLACK: Color = Color(0, 0, 0);
+
+#[test]
+fn test_color() {
+    assert_eq!(Some(Color(0x70, 0xc7, 0x10)), Color::parse_hex("#70c710"));
+    assert_eq!(None, Color::parse_hex("70c710"));
+    assert_eq!(None, Color::parse_hex("#70c71"));
+    assert_eq!(Color(128, 128, 128), BLACK.mix(Color::WHITE, 0.5));
+    assert_eq!(Color::WHITE, BLACK.mix(Color::WHITE, 2.0));
+}
+
+#[test]
+fn test_to_ppm() {
+    let mut image = Image::new(2, 1, BLACK);
+    image.set(1, 0, Color::WHITE);
+    image.set(5, 5, Color::RED);
+
+    let ppm = image.to_ppm();
+    let header = format!("P6\n{} {CELL}\n255\n", 2 * CELL);
+    assert!(ppm.starts_with(header.as_bytes()));
+
+    let pixels = &ppm[header.len()..];
+    assert_eq!(2 * CELL * CELL * 3, pixels.len());
+    assert_eq!([0, 0, 0], pixels[..3]);
+    assert_eq!([255, 255, 255], pixels[CELL * 3..CELL * 3 + 3]);
+}
+
+#[test]
+fn test_to_svg() {
+    let mut image = Image::new(3, 1, BLACK);
+    image.set(2, 0, Color::WHITE);
+
+    let svg = image.to_svg();
+    assert!(svg.contains("viewBox=\"0 0 3 1\""));
+    assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#000000\"/>"));
+    assert!(svg.contains("<rect x=\"2\" y=\"0\" width=\"1\" height=\"1\" fill=\"#ffffff\"/>"));
+    assert!(svg.ends_with("</svg>\n"));
+}
+
+#[test]
+fn test_format() {
+    assert_eq!(Some(Format::Svg), Format::from_path(Path::new("out.SVG")));
+    assert_eq!(Some(Format::Ppm), Format::from_path(Path::new("a/b.ppm")));
+    assert_eq!(None, Format::from_path(Path::new("out.png")));
+    assert_eq!(None, Format::from_path(Path::new("out")));
+}
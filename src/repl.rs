@@ -0,0 +1,289 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    answer::Solution,
+    params::{self, Params},
+    registry, runner, y2023,
+};
+
+/// A parsed puzzle input that can be solved and inspected from the REPL.
+pub trait Model {
+    fn part1(&self) -> Solution;
+
+    fn part2(&self) -> Solution;
+
+    /// The names of the views that `show` accepts.
+    fn views(&self) -> Vec<&'static str>;
+
+    fn show(&self, view: &str) -> Option<String>;
+}
+
+pub type Parse = fn(&str) -> Result<Box<dyn Model>>;
+
+/// The days that have a model to show.
+const MODELS: &[(u32, u32, Parse)] = &[
+    (2023, 10, y2023::day10::model),
+    (2023, 14, y2023::day14::model),
+    (2023, 20, y2023::day20::model),
+];
+
+const HELP: &str = "commands:
+  run <day> [part]          run one or both parts of a day
+  show <day> [view]         show a view of the parsed input of a day
+  load <day> <path>         use another input for a day
+  set param <name>=<value>  override a param of the solvers
+  unset param <name>        go back to the default of a param
+  params                    list the overridden params
+  reload                    read the inputs again and drop the parsed models
+  help                      show this help
+  quit                      leave the REPL
+";
+
+/// Keeps the inputs and parsed models in memory between commands, so exploring
+/// a day does not require starting over for every run.
+pub struct Repl {
+    year: u32,
+    paths: HashMap<u32, PathBuf>,
+    inputs: HashMap<u32, String>,
+    models: HashMap<u32, Box<dyn Model>>,
+    params: Params,
+}
+
+/// Reads commands from stdin until it is closed or `quit` is entered.
+pub fn run(year: u32) -> Result<()> {
+    let mut repl = Repl::new(year);
+    let mut stdin = io::stdin().lock();
+
+    loop {
+        print!("> ");
+        io::stdout().flush().map_err(|err| err.to_string())?;
+
+        let mut line = String::new();
+        if stdin.read_line(&mut line).map_err(|err| err.to_string())? == 0 {
+            return Ok(());
+        }
+
+        let line = line.trim();
+        if line == "quit" || line == "exit" {
+            return Ok(());
+        }
+
+        match repl.execute(line) {
+            Ok(out) => print!("{out}"),
+            Err(err) => eprintln!("error: {err}"),
+        }
+    }
+}
+
+impl Repl {
+    pub fn new(year: u32) -> Self {
+        Repl {
+            year,
+            paths: HashMap::new(),
+            inputs: HashMap::new(),
+            models: HashMap::new(),
+            params: Params::new(),
+        }
+    }
+
+    /// Executes a single command and returns its output.
+    pub fn execute(&mut self, line: &str) -> Result<String> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+
+        match words.as_slice() {
+            [] => Ok(String::new()),
+            ["help"] => Ok(HELP.to_string()),
+            ["run", day] => {
+                let day = parse_day(day)?;
+                Ok(format!("{}{}", self.run(day, 1)?, self.run(day, 2)?))
+            }
+            ["run", day, part] => {
+                let part = registry::parse_part(part).ok_or(format!("invalid part {part}"))?;
+                self.run(parse_day(day)?, part)
+            }
+            ["show", day] => {
+                let model = self.model(parse_day(day)?)?;
+                Ok(format!("views: {}\n", model.views().join(", ")))
+            }
+            ["show", day, view] => {
+                let model = self.model(parse_day(day)?)?;
+                model.show(view).ok_or(format!(
+                    "unknown view {view}, expected one of: {}",
+                    model.views().join(", ")
+                ))
+            }
+            ["load", day, path] => {
+                let day = parse_day(day)?;
+                let input = fs::read_to_string(path).map_err(|err| format!("{path}: {err}"))?;
+
+                self.paths.insert(day, PathBuf::from(path));
+                self.inputs.insert(day, input);
+                self.models.remove(&day);
+
+                Ok(format!("loaded {path} for day{day}\n"))
+            }
+            ["set", "param", param @ ..] => {
+                let (name, value) = params::parse(&param.join(" "))?;
+                self.params.insert(name, value);
+                Ok(String::new())
+            }
+            ["unset", "param", name] => {
+                self.params.remove(*name);
+                Ok(String::new())
+            }
+            ["params"] => Ok(self
+                .params
+                .iter()
+                .map(|(name, value)| format!("{name} = {value}\n"))
+                .collect()),
+            ["reload"] => {
+                self.inputs.clear();
+                self.models.clear();
+                Ok("dropped the inputs and models\n".to_string())
+            }
+            _ => Err(format!("unknown command '{line}', try 'help'")),
+        }
+    }
+
+    fn run(&mut self, day: u32, part: u32) -> Result<String> {
+        let solver = registry::find(self.year, day, part).ok_or(format!(
+            "Invalid year {}, day {day} or part {part}",
+            self.year
+        ))?;
+
+        // Days with a model are parsed once, the others are solved from the
+        // input kept in memory.
+        let outcome = if parse_model(self.year, day).is_some() {
+            self.model(day)?;
+            let model = self.models[&day].as_ref();
+            params::with(&self.params, || {
+                runner::run_fn(|| match part {
+                    1 => model.part1(),
+                    _ => model.part2(),
+                })
+            })
+        } else {
+            self.input(day)?;
+            let input = &self.inputs[&day];
+            params::with(&self.params, || runner::run(&solver, input))
+        };
+
+        Ok(format!(
+            "part{part}: {} ({})\n",
+            outcome.answer.map_err(|err| err.to_string())?,
+            runner::format_duration(outcome.elapsed)
+        ))
+    }
+
+    fn input(&mut self, day: u32) -> Result<&str> {
+        if !self.inputs.contains_key(&day) {
+            let path = self
+                .paths
+                .get(&day)
+                .cloned()
+                .unwrap_or_else(|| registry::input_path(self.year, day));
+            let input =
+                fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+
+            self.inputs.insert(day, input);
+        }
+
+        Ok(&self.inputs[&day])
+    }
+
+    fn model(&mut self, day: u32) -> Result<&dyn Model> {
+        if !self.models.contains_key(&day) {
+            let parse = parse_model(self.year, day).ok_or(format!(
+                "day{day} has no model to show, the days with one are {}",
+                MODELS
+                    .iter()
+                    .filter(|(y, _, _)| *y == self.year)
+                    .map(|(_, d, _)| d)
+                    .join(", ")
+            ))?;
+
+            let model = parse(self.input(day)?)?;
+            self.models.insert(day, model);
+        }
+
+        Ok(self.models[&day].as_ref())
+    }
+}
+
+fn parse_model(year: u32, day: u32) -> Option<Parse> {
+    MODELS
+        .iter()
+        .find(|(y, d, _)| *y == year && *d == day)
+        .map(|&(_, _, parse)| parse)
+}
+
+fn parse_day(s: &str) -> Result<u32> {
+    registry::parse_day(s).ok_or(format!("invalid day {s}"))
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[test]
+fn test_execute() {
+    let mut repl = Repl::new(2023);
+
+    repl.execute("load day14 examples/2023/day14/sample.txt")
+        .unwrap();
+    assert!(repl
+        .execute("run day14 part1")
+        .unwrap()
+        .starts_with("part1: 136 "));
+    assert!(repl.models.contains_key(&14));
+    assert!(repl
+        .execute("show day14 board")
+        .unwrap()
+        .starts_with("O....#....\n"));
+    assert!(repl.execute("show day14 nothing").is_err());
+
+    repl.execute("set param cycles=1").unwrap();
+    assert_eq!("cycles = 1\n", repl.execute("params").unwrap());
+    assert!(repl
+        .execute("run day14 part2")
+        .unwrap()
+        .starts_with("part2: 87 "));
+
+    repl.execute("unset param cycles").unwrap();
+    assert!(repl
+        .execute("run day14 part2")
+        .unwrap()
+        .starts_with("part2: 64 "));
+
+    repl.execute("load day21 examples/2023/day21/sample.txt")
+        .unwrap();
+    repl.execute("set param steps=6").unwrap();
+    assert!(repl
+        .execute("run day21 part1")
+        .unwrap()
+        .starts_with("part1: 16 "));
+
+    assert_eq!(
+        Err("day1 has no model to show, the days with one are 10, 14, 20".to_string()),
+        repl.execute("show day1")
+    );
+    assert!(repl.execute("run day26").is_err());
+    assert!(repl.execute("frobnicate").is_err());
+
+    // Inputs loaded from another path are read again from that path.
+    repl.execute("reload").unwrap();
+    assert!(repl
+        .execute("run day14 part1")
+        .unwrap()
+        .starts_with("part1: 136 "));
+}
@@ -0,0 +1,371 @@
+use std::{fs, path::Path, time::Duration};
+
+use clap::ValueEnum;
+
+use crate::{
+    alloc,
+    answer::Answer,
+    fixtures, registry,
+    runner::{self, Failure, Outcome},
+};
+
+/// The report is written between these markers when updating an existing file,
+/// so it can live in a section of the README.
+pub const START_MARKER: &str = "<!-- report -->";
+pub const END_MARKER: &str = "<!-- /report -->";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Markdown,
+    Html,
+}
+
+pub struct Options {
+    pub year: u32,
+    pub days: Vec<u32>,
+    pub format: Format,
+    pub redact: bool,
+    pub timeout: Duration,
+}
+
+struct Entry {
+    day: u32,
+    part: u32,
+    outcome: Outcome,
+    expected: Option<Answer>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    /// The answer matches the known answer.
+    Pass,
+    /// There is an answer, but no known answer to check it against.
+    Unchecked,
+    /// The answer is wrong, or there is no answer.
+    Fail,
+}
+
+/// Runs both parts of the selected days on their inputs and renders a report
+/// with the stars, answers, timings and allocations.
+pub fn report(options: &Options) -> String {
+    let mut entries = Vec::new();
+
+    for &day in options.days.iter() {
+        let input = fs::read_to_string(registry::input_path(options.year, day));
+        let expected = fixtures::read_expected(&registry::answers_path(options.year, day));
+
+        for part in [1, 2]
+            .into_iter()
+            .filter(|&part| registry::has_puzzle(day, part))
+        {
+            let Some(solver) = registry::find(options.year, day, part) else {
+                continue;
+            };
+
+            let outcome = match (&input, &expected) {
+                (Ok(input), Ok(_)) => runner::run_with_timeout(&solver, input, options.timeout),
+                (Err(err), _) => failed(format!("no input: {err}")),
+                (_, Err(err)) => failed(err.clone()),
+            };
+
+            entries.push(Entry {
+                day,
+                part,
+                outcome,
+                expected: expected.as_ref().ok().and_then(|e| e.get(&part).cloned()),
+            });
+        }
+    }
+
+    match options.format {
+        Format::Markdown => render_markdown(options.year, &entries, options.redact),
+        Format::Html => render_html(options.year, &entries, options.redact),
+    }
+}
+
+/// Replaces the section between the markers with the report.
+pub fn update_section(contents: &str, report: &str) -> Result<String> {
+    let missing = || format!("expected a section between {START_MARKER} and {END_MARKER}");
+
+    let start = contents.find(START_MARKER).ok_or_else(missing)? + START_MARKER.len();
+    let end = contents[start..].find(END_MARKER).ok_or_else(missing)? + start;
+
+    Ok(format!(
+        "{}\n{}\n{}",
+        &contents[..start],
+        report.trim_end(),
+        &contents[end..]
+    ))
+}
+
+/// Writes the report to the file, only replacing the report section if the
+/// file already exists.
+pub fn write(path: &Path, report: &str) -> Result<()> {
+    let contents = if path.exists() {
+        let contents =
+            fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        update_section(&contents, report).map_err(|err| format!("{}: {err}", path.display()))?
+    } else {
+        report.to_string()
+    };
+
+    fs::write(path, contents).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Parses a list of days such as "1-5,7".
+pub fn parse_days(s: &str) -> Result<Vec<u32>> {
+    let mut days = Vec::new();
+
+    for range in s.split(',') {
+        let parse = |d: &str| {
+            d.trim()
+                .parse::<u32>()
+                .map_err(|_| format!("invalid day '{}'", d.trim()))
+        };
+
+        match range.split_once('-') {
+            Some((from, to)) => days.extend(parse(from)?..=parse(to)?),
+            None => days.push(parse(range)?),
+        }
+    }
+
+    days.sort();
+    days.dedup();
+
+    Ok(days)
+}
+
+fn failed(err: String) -> Outcome {
+    Outcome {
+        answer: Err(Failure::Error(err)),
+        elapsed: Duration::ZERO,
+        allocs: alloc::Stats::default(),
+    }
+}
+
+impl Entry {
+    fn status(&self) -> Status {
+        match (&self.outcome.answer, &self.expected) {
+            (Ok(answer), Some(expected)) if answer == expected => Status::Pass,
+            (Ok(_), None) => Status::Unchecked,
+            _ => Status::Fail,
+        }
+    }
+
+    fn answer(&self, redact: bool) -> String {
+        match &self.outcome.answer {
+            Ok(_) if redact => "redacted".to_string(),
+            Ok(answer) => answer.to_string(),
+            Err(_) => "-".to_string(),
+        }
+    }
+
+    fn problem(&self) -> Option<String> {
+        match (&self.outcome.answer, &self.expected) {
+            (Err(err), _) => Some(err.to_string()),
+            (Ok(answer), Some(expected)) if answer != expected => {
+                Some(format!("expected {expected}, got {answer}"))
+            }
+            _ => None,
+        }
+    }
+
+    /// The cells of the row of the entry, the answer is redacted if asked.
+    fn cells(&self, redact: bool) -> Vec<String> {
+        let allocs = &self.outcome.allocs;
+
+        vec![
+            self.day.to_string(),
+            self.part.to_string(),
+            self.status().symbol().to_string(),
+            self.answer(redact),
+            runner::format_duration(self.outcome.elapsed),
+            allocs.count.to_string(),
+            alloc::format_bytes(allocs.peak),
+        ]
+    }
+}
+
+impl Status {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Status::Pass => "★",
+            Status::Unchecked => "☆",
+            Status::Fail => "✗",
+        }
+    }
+}
+
+const HEADER: [&str; 7] = [
+    "Day",
+    "Part",
+    "Star",
+    "Answer",
+    "Time",
+    "Allocations",
+    "Peak memory",
+];
+
+fn summary(entries: &[Entry]) -> String {
+    let count = |status| entries.iter().filter(|e| e.status() == status).count();
+
+    format!(
+        "{} ★ matching the known answer, {} ☆ without a known answer, {} ✗ wrong or failed",
+        count(Status::Pass),
+        count(Status::Unchecked),
+        count(Status::Fail)
+    )
+}
+
+fn problems(entries: &[Entry]) -> Vec<String> {
+    entries
+        .iter()
+        .filter_map(|e| Some(format!("day{} part{}: {}", e.day, e.part, e.problem()?)))
+        .collect()
+}
+
+fn render_markdown(year: u32, entries: &[Entry], redact: bool) -> String {
+    let row = |cells: &[String]| format!("| {} |\n", cells.join(" | "));
+
+    let mut out = format!("### Advent of Code {year}\n\n");
+    out.push_str(&row(&HEADER.map(|h| h.to_string())));
+    out.push_str("| --: | --: | :-: | --- | --: | --: | --: |\n");
+    for e in entries {
+        out.push_str(&row(&e
+            .cells(redact)
+            .iter()
+            .map(|c| c.replace('|', "\\|"))
+            .collect::<Vec<_>>()));
+    }
+
+    out.push_str(&format!("\n{}\n", summary(entries)));
+
+    let problems = problems(entries);
+    if !problems.is_empty() {
+        out.push('\n');
+        for p in problems {
+            out.push_str(&format!("- {}\n", p.replace('|', "\\|")));
+        }
+    }
+
+    out
+}
+
+fn render_html(year: u32, entries: &[Entry], redact: bool) -> String {
+    let row = |tag: &str, cells: &[String]| {
+        let cells = cells
+            .iter()
+            .map(|c| format!("<{tag}>{}</{tag}>", escape_html(c)))
+            .collect::<String>();
+        format!("<tr>{cells}</tr>\n")
+    };
+
+    let mut out = format!("<h3>Advent of Code {year}</h3>\n<table>\n");
+    out.push_str(&row("th", &HEADER.map(|h| h.to_string())));
+    for e in entries {
+        out.push_str(&row("td", &e.cells(redact)));
+    }
+    out.push_str("</table>\n");
+
+    out.push_str(&format!("<p>{}</p>\n", summary(entries)));
+
+    let problems = problems(entries);
+    if !problems.is_empty() {
+        out.push_str("<ul>\n");
+        for p in problems {
+            out.push_str(&format!("<li>{}</li>\n", escape_html(&p)));
+        }
+        out.push_str("</ul>\n");
+    }
+
+    out
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[cfg(test)]
+fn test_entries() -> Vec<Entry> {
+    let entry = |part, answer: std::result::Result<&str, &str>, expected: Option<&str>| Entry {
+        day: 1,
+        part,
+        outcome: Outcome {
+            answer: answer
+                .map(Answer::parse)
+                .map_err(|e| Failure::Error(e.to_string())),
+            elapsed: Duration::from_micros(850),
+            allocs: alloc::Stats {
+                count: 3,
+                bytes: 2048,
+                peak: 1536,
+            },
+        },
+        expected: expected.map(Answer::parse),
+    };
+
+    vec![
+        entry(1, Ok("142"), Some("142")),
+        entry(2, Ok("281"), None),
+        entry(3, Ok("1"), Some("2")),
+        entry(4, Err("timed out <10s>"), Some("2")),
+    ]
+}
+
+#[test]
+fn test_render_markdown() {
+    assert_eq!(
+        "### Advent of Code 2023
+
+| Day | Part | Star | Answer | Time | Allocations | Peak memory |
+| --: | --: | :-: | --- | --: | --: | --: |
+| 1 | 1 | ★ | 142 | 850µs | 3 | 1.5KiB |
+| 1 | 2 | ☆ | 281 | 850µs | 3 | 1.5KiB |
+| 1 | 3 | ✗ | 1 | 850µs | 3 | 1.5KiB |
+| 1 | 4 | ✗ | - | 850µs | 3 | 1.5KiB |
+
+1 ★ matching the known answer, 1 ☆ without a known answer, 2 ✗ wrong or failed
+
+- day1 part3: expected 2, got 1
+- day1 part4: timed out <10s>
+",
+        render_markdown(2023, &test_entries(), false)
+    );
+
+    assert!(render_markdown(2023, &test_entries(), true).contains("| 1 | 1 | ★ | redacted |"));
+}
+
+#[test]
+fn test_render_html() {
+    let html = render_html(2023, &test_entries(), false);
+
+    assert!(html.contains("<tr><td>1</td><td>1</td><td>★</td><td>142</td>"));
+    assert!(html.contains("<li>day1 part4: timed out &lt;10s&gt;</li>"));
+}
+
+#[test]
+fn test_update_section() {
+    let readme = "# aoc\n\n<!-- report -->\nold\n<!-- /report -->\n\n## More\n";
+
+    assert_eq!(
+        "# aoc\n\n<!-- report -->\nnew\n<!-- /report -->\n\n## More\n",
+        update_section(readme, "new\n").unwrap()
+    );
+    assert!(update_section("# aoc\n", "new").is_err());
+}
+
+#[test]
+fn test_parse_days() {
+    assert_eq!(Ok(vec![1, 2, 3, 5, 7]), parse_days("5,1-3,7,2"));
+    assert!(parse_days("1-x").is_err());
+}
@@ -0,0 +1,79 @@
+use std::{cell::RefCell, sync::Arc};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::registry::Solver;
+
+/// The seed of solvers that run outside of `with`, e.g. in tests and reports,
+/// so their answers do not change from run to run either.
+pub const DEFAULT_SEED: u64 = 0;
+
+thread_local! {
+    static RNG: RefCell<StdRng> = RefCell::new(StdRng::seed_from_u64(DEFAULT_SEED));
+}
+
+/// Gives `f` the random number generator of the solvers. Solvers draw all
+/// their random numbers from it, so a run can be replayed from its seed.
+///
+/// Example:
+///    let i = rng::draw(|rng| rng.gen_range(0..edges.len()));
+pub fn draw<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+    RNG.with(|rng| f(&mut rng.borrow_mut()))
+}
+
+/// Runs `f` with the random number generator seeded with `seed`.
+pub fn with<T>(seed: u64, f: impl FnOnce() -> T) -> T {
+    let previous = RNG.with(|rng| rng.replace(StdRng::seed_from_u64(seed)));
+    let result = f();
+    RNG.with(|rng| rng.replace(previous));
+
+    result
+}
+
+/// Wraps a solver so it always runs with `seed`, also when it is run on
+/// another thread.
+pub fn seeded(solver: &Solver, seed: u64) -> Solver {
+    let solve = solver.solve.clone();
+
+    Solver {
+        solve: Arc::new(move |input| with(seed, || solve(input))),
+        ..solver.clone()
+    }
+}
+
+/// Picks a seed for a run that was not given one.
+pub fn random_seed() -> u64 {
+    rand::thread_rng().gen()
+}
+
+#[test]
+fn test_with() {
+    let numbers = || {
+        (0..4)
+            .map(|_| draw(|rng| rng.gen::<u64>()))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(with(7, numbers), with(7, numbers));
+    assert_ne!(with(7, numbers), with(8, numbers));
+}
+
+#[test]
+fn test_seeded() {
+    let solver = Solver {
+        year: 2023,
+        day: 25,
+        part: 1,
+        name: "random".to_string(),
+        solve: Arc::new(|_| Ok(draw(|rng| rng.gen::<u32>()).into())),
+    };
+
+    let solver = seeded(&solver, 7);
+    let answer = (solver.solve)("").unwrap();
+    assert_eq!(
+        answer,
+        std::thread::spawn(move || (solver.solve)("").unwrap())
+            .join()
+            .unwrap()
+    );
+}
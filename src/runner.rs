@@ -0,0 +1,235 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Once},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    alloc,
+    answer::{Answer, Solution, Unsolved},
+    registry::Solver,
+};
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+pub struct Outcome {
+    pub answer: Result<Answer, Failure>,
+    pub elapsed: Duration,
+    pub allocs: alloc::Stats,
+}
+
+/// Why a run did not give an answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Failure {
+    /// The solver could not parse the input.
+    Parse(String),
+    /// The solver parsed the input but found no answer in it.
+    Error(String),
+    Panic {
+        location: String,
+        message: String,
+    },
+    Timeout(Duration),
+    /// The thread for the solver could not be started.
+    Spawn(String),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Parse(err) | Failure::Error(err) => write!(f, "{err}"),
+            Failure::Panic { location, message } => write!(f, "panicked at {location}: {message}"),
+            Failure::Timeout(timeout) => write!(f, "timed out after {}", format_duration(*timeout)),
+            Failure::Spawn(err) => write!(f, "could not start the solver: {err}"),
+        }
+    }
+}
+
+/// Runs a solver on the input, turning a panic into an error so a single bad
+/// input does not take down the caller.
+pub fn run(solver: &Solver, input: &str) -> Outcome {
+    run_fn(|| (solver.solve)(input))
+}
+
+/// Like `run`, for a part that is solved some other way than from the input,
+/// e.g. from a model parsed earlier.
+pub fn run_fn(solve: impl FnOnce() -> Solution) -> Outcome {
+    install_panic_hook();
+
+    let catching = CATCHING.replace(true);
+    let start = Instant::now();
+    let (result, allocs) = alloc::measure(|| panic::catch_unwind(AssertUnwindSafe(solve)));
+    let elapsed = start.elapsed();
+    CATCHING.set(catching);
+
+    let answer = match result {
+        Ok(answer) => answer.map_err(|err| match err {
+            Unsolved::Parse(err) => Failure::Parse(err),
+            Unsolved::Failed(err) => Failure::Error(err),
+        }),
+        Err(payload) => Err(Failure::Panic {
+            location: LOCATION
+                .take()
+                .unwrap_or_else(|| "unknown location".to_string()),
+            message: panic_message(payload.as_ref()),
+        }),
+    };
+
+    Outcome {
+        answer,
+        elapsed,
+        allocs,
+    }
+}
+
+/// Like `run`, but gives up waiting after `timeout`. Threads cannot be
+/// stopped, so a solver that times out keeps running in the background until
+/// it finishes or the process exits.
+///
+/// The solver runs on another thread, so it does not see the params or trace
+/// capture of the caller.
+pub fn run_with_timeout(solver: &Solver, input: &str, timeout: Duration) -> Outcome {
+    let (tx, rx) = mpsc::channel();
+    let solver = solver.clone();
+    let input = input.to_string();
+
+    // Some solvers recurse deeply, so give them as much stack as the main
+    // thread has.
+    let spawned = thread::Builder::new()
+        .stack_size(8 << 20)
+        .spawn(move || tx.send(run(&solver, &input)));
+
+    if let Err(err) = spawned {
+        return Outcome {
+            answer: Err(Failure::Spawn(err.to_string())),
+            elapsed: Duration::ZERO,
+            allocs: alloc::Stats::default(),
+        };
+    }
+
+    rx.recv_timeout(timeout).unwrap_or_else(|_| Outcome {
+        answer: Err(Failure::Timeout(timeout)),
+        elapsed: timeout,
+        allocs: alloc::Stats::default(),
+    })
+}
+
+/// Formats a duration with a unit that keeps it short, e.g. "850µs" or "1.2s".
+pub fn format_duration(d: Duration) -> String {
+    let micros = d.as_micros();
+
+    match micros {
+        0..=999 => format!("{micros}µs"),
+        1_000..=999_999 => format!("{:.1}ms", micros as f64 / 1_000.0),
+        _ => format!("{:.2}s", d.as_secs_f64()),
+    }
+}
+
+/// Wraps the default panic hook so panics caught by `run` are only reported
+/// through the outcome, instead of also printing a message and backtrace.
+fn install_panic_hook() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                let location = info.location().map(|l| l.to_string());
+                LOCATION.set(location);
+            } else {
+                default(info);
+            }
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+use std::sync::Arc;
+
+#[test]
+fn test_run() {
+    let solver = Solver {
+        year: 2023,
+        day: 1,
+        part: 1,
+        name: "len".to_string(),
+        solve: Arc::new(|input| Ok(input.len().into())),
+    };
+    assert_eq!(Ok(Answer::Int(3)), run(&solver, "abc").answer);
+
+    let solver = Solver {
+        solve: Arc::new(|_| panic!("bad input")),
+        ..solver
+    };
+    let answer = run(&solver, "abc").answer.unwrap_err().to_string();
+    assert!(answer.starts_with("panicked at src/runner.rs:"), "{answer}");
+    assert!(answer.ends_with(": bad input"), "{answer}");
+
+    let solver = Solver {
+        solve: Arc::new(|_| Err("no digits".into())),
+        ..solver
+    };
+    let answer = run(&solver, "abc").answer;
+    assert_eq!(Err(Failure::Error("no digits".to_string())), answer);
+
+    let solver = Solver {
+        solve: Arc::new(|_| Err(Unsolved::Parse("invalid line: abc".to_string()))),
+        ..solver
+    };
+    let answer = run(&solver, "abc").answer;
+    assert_eq!(Err(Failure::Parse("invalid line: abc".to_string())), answer);
+}
+
+#[test]
+fn test_run_with_timeout() {
+    let solver = Solver {
+        year: 2023,
+        day: 1,
+        part: 1,
+        name: "len".to_string(),
+        solve: Arc::new(|input| Ok(input.len().into())),
+    };
+    let outcome = run_with_timeout(&solver, "abc", Duration::from_secs(10));
+    assert_eq!(Ok(Answer::Int(3)), outcome.answer);
+
+    let solver = Solver {
+        solve: Arc::new(|_| {
+            thread::sleep(Duration::from_secs(1));
+            Ok(0.into())
+        }),
+        ..solver
+    };
+    let outcome = run_with_timeout(&solver, "abc", Duration::from_millis(10));
+    assert_eq!(
+        Err(Failure::Timeout(Duration::from_millis(10))),
+        outcome.answer
+    );
+    assert_eq!(
+        "timed out after 10.0ms",
+        outcome.answer.unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!("850µs", format_duration(Duration::from_micros(850)));
+    assert_eq!("12.5ms", format_duration(Duration::from_micros(12_500)));
+    assert_eq!("1.20s", format_duration(Duration::from_millis(1_200)));
+}
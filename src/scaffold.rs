@@ -0,0 +1,332 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const DAY_TEMPLATE: &str = r#"use crate::{answer::Solution, util};
+
+pub fn part1(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input);
+
+    Err(format!("day{DAY} part1 is not solved yet ({} lines)", lines.count()).into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input);
+
+    Err(format!("day{DAY} part2 is not solved yet ({} lines)", lines.count()).into())
+}
+"#;
+
+const EXPECTED_TEMPLATE: &str = "# part1 = \n# part2 = \n";
+
+/// Checks that there is a puzzle for the day.
+pub fn check_day(year: u32, day: u32) -> Result<()> {
+    if year < 2015 {
+        return Err(format!("there is no advent of code in {year}"));
+    }
+    if !(1..=25).contains(&day) {
+        return Err(format!("day must be between 1 and 25, got {day}"));
+    }
+
+    Ok(())
+}
+
+/// Creates the module, input and example placeholders for a new day, and
+/// registers the module. The module for the year is created as well if this is
+/// the first day of a new year. Nothing is written if any of the files already
+/// exist.
+///
+/// Returns the paths of the files that were created.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>> {
+    check_day(year, day)?;
+
+    let module = format!("day{day}");
+    let year_module = format!("y{year}");
+
+    let mut files = vec![
+        (
+            root.join(format!("src/{year_module}/{module}.rs")),
+            DAY_TEMPLATE.replace("{DAY}", &day.to_string()),
+        ),
+        (
+            root.join(format!("inputs/{year}/{module}.txt")),
+            String::new(),
+        ),
+        (
+            root.join(format!("examples/{year}/{module}/sample.txt")),
+            String::new(),
+        ),
+        (
+            root.join(format!("examples/{year}/{module}/sample.expected")),
+            EXPECTED_TEMPLATE.to_string(),
+        ),
+    ];
+
+    let existing = files
+        .iter()
+        .filter(|(path, _)| path.exists())
+        .map(|(path, _)| path.display().to_string())
+        .collect::<Vec<_>>();
+
+    if !existing.is_empty() {
+        return Err(format!("refusing to overwrite {}", existing.join(", ")));
+    }
+
+    let mod_path = root.join(format!("src/{year_module}/mod.rs"));
+    let lib_path = root.join("src/lib.rs");
+    let registry_path = root.join("src/registry.rs");
+
+    // Prepare the registration edits before writing anything, so a failure
+    // does not leave a half registered day behind.
+    let mut edits = Vec::new();
+
+    if mod_path.exists() {
+        edits.push((
+            mod_path.clone(),
+            insert_sorted(
+                &read(&mod_path)?,
+                &format!("pub mod {module};"),
+                mod_day,
+                day,
+            )?,
+        ));
+    } else {
+        files.push((mod_path, format!("pub mod {module};\n")));
+        edits.push((
+            lib_path.clone(),
+            insert_sorted(
+                &read(&lib_path)?,
+                &format!("pub mod {year_module};"),
+                root_year,
+                year,
+            )?,
+        ));
+    }
+
+    edits.push((
+        registry_path.clone(),
+        register(&read(&registry_path)?, year, day)?,
+    ));
+
+    for (path, contents) in files.iter().chain(edits.iter()) {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+        }
+        write(path, contents)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Adds the day to the block of its year in the registry, or adds a new block
+/// if it is the first day of the year.
+fn register(registry: &str, year: u32, day: u32) -> Result<String> {
+    let lines = registry.lines().collect::<Vec<_>>();
+
+    // Find the start and end lines of each year block.
+    let blocks = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((registry_year(l)?, i)))
+        .map(|(y, start)| {
+            let end = lines[start..]
+                .iter()
+                .position(|l| l.trim_end() == "    }")
+                .map(|offset| start + offset)
+                .ok_or(format!("the block for {y} is never closed"))?;
+
+            Ok((y, start, end))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut result = Vec::new();
+
+    if let Some(&(_, start, end)) = blocks.iter().find(|(y, _, _)| *y == year) {
+        let block = insert_sorted(
+            &lines[start + 1..end].join("\n"),
+            &format!("        {day} => day{day},"),
+            registry_day,
+            day,
+        )?;
+
+        result.extend(lines[..=start].iter().map(|l| l.to_string()));
+        result.extend(block.lines().map(|l| l.to_string()));
+        result.extend(lines[end..].iter().map(|l| l.to_string()));
+    } else {
+        let position = blocks
+            .iter()
+            .filter(|(y, _, _)| *y < year)
+            .map(|&(_, _, end)| end + 1)
+            .max()
+            .or(blocks.first().map(|&(_, start, _)| start))
+            .ok_or("could not find the registered years".to_string())?;
+
+        result.extend(lines[..position].iter().map(|l| l.to_string()));
+        result.push(format!("    {year} => y{year} {{"));
+        result.push(format!("        {day} => day{day},"));
+        result.push("    }".to_string());
+        result.extend(lines[position..].iter().map(|l| l.to_string()));
+    }
+
+    Ok(result.join("\n") + "\n")
+}
+
+/// Inserts `line` after the last line that belongs to a day before `day`, as
+/// identified by `day_of`.
+fn insert_sorted(
+    contents: &str,
+    line: &str,
+    day_of: impl Fn(&str) -> Option<u32>,
+    day: u32,
+) -> Result<String> {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+
+    if lines.iter().any(|l| day_of(l) == Some(day)) {
+        return Err(format!("day{day} is already registered"));
+    }
+
+    let position = lines
+        .iter()
+        .rposition(|l| day_of(l).is_some_and(|d| d < day))
+        .map(|i| i + 1)
+        .or_else(|| lines.iter().position(|l| day_of(l).is_some()))
+        .ok_or(format!("could not find where to register day{day}"))?;
+
+    lines.insert(position, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn mod_day(line: &str) -> Option<u32> {
+    line.strip_prefix("pub mod day")?
+        .strip_suffix(';')?
+        .parse()
+        .ok()
+}
+
+fn root_year(line: &str) -> Option<u32> {
+    line.strip_prefix("pub mod y")?
+        .strip_suffix(';')?
+        .parse()
+        .ok()
+}
+
+fn registry_year(line: &str) -> Option<u32> {
+    let (year, module) = line.trim().strip_suffix(" {")?.split_once(" => ")?;
+    let year = year.parse().ok()?;
+
+    (module == format!("y{year}")).then_some(year)
+}
+
+fn registry_day(line: &str) -> Option<u32> {
+    let (day, module) = line.trim().split_once(" => ")?;
+    let day = day.parse().ok()?;
+
+    (module == format!("day{day},")).then_some(day)
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[test]
+fn test_insert_sorted() {
+    let mods = "pub mod day1;\npub mod day3;\n\npub mod day10;\n";
+
+    assert_eq!(
+        "pub mod day1;\npub mod day2;\npub mod day3;\n\npub mod day10;\n",
+        insert_sorted(mods, "pub mod day2;", mod_day, 2).unwrap()
+    );
+    assert_eq!(
+        "pub mod day1;\npub mod day3;\n\npub mod day10;\npub mod day11;\n",
+        insert_sorted(mods, "pub mod day11;", mod_day, 11).unwrap()
+    );
+    assert!(insert_sorted(mods, "pub mod day3;", mod_day, 3).is_err());
+}
+
+#[cfg(test)]
+const TEST_REGISTRY: &str = "register! {
+    2023 => y2023 {
+        1 => day1,
+        3 => day3,
+    }
+}
+";
+
+#[test]
+fn test_register() {
+    assert_eq!(
+        "register! {\n    2023 => y2023 {\n        1 => day1,\n        2 => day2,\n        3 => day3,\n    }\n}\n",
+        register(TEST_REGISTRY, 2023, 2).unwrap()
+    );
+    assert_eq!(
+        "register! {\n    2023 => y2023 {\n        1 => day1,\n        3 => day3,\n    }\n    2024 => y2024 {\n        5 => day5,\n    }\n}\n",
+        register(TEST_REGISTRY, 2024, 5).unwrap()
+    );
+    assert_eq!(
+        "register! {\n    2022 => y2022 {\n        5 => day5,\n    }\n    2023 => y2023 {\n        1 => day1,\n        3 => day3,\n    }\n}\n",
+        register(TEST_REGISTRY, 2022, 5).unwrap()
+    );
+    assert!(register(TEST_REGISTRY, 2023, 3).is_err());
+}
+
+#[test]
+fn test_new_day() {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    fs::create_dir_all(root.join("src/y2023")).unwrap();
+    fs::write(root.join("src/lib.rs"), "mod python;\npub mod y2023;\n").unwrap();
+    fs::write(
+        root.join("src/y2023/mod.rs"),
+        "pub mod day1;\npub mod day3;\n",
+    )
+    .unwrap();
+    fs::write(root.join("src/registry.rs"), TEST_REGISTRY).unwrap();
+
+    let created = new_day(&root, 2023, 2).unwrap();
+    assert_eq!(4, created.len());
+    assert!(created.iter().all(|p| p.exists()));
+    assert!(read(&root.join("src/y2023/day2.rs"))
+        .unwrap()
+        .contains("day2 part1 is not solved yet"));
+    assert_eq!(
+        "pub mod day1;\npub mod day2;\npub mod day3;\n",
+        read(&root.join("src/y2023/mod.rs")).unwrap()
+    );
+
+    // Running it again must not touch the existing files.
+    fs::write(root.join("inputs/2023/day2.txt"), "my input").unwrap();
+    assert!(new_day(&root, 2023, 2).is_err());
+    assert_eq!(
+        "my input",
+        read(&root.join("inputs/2023/day2.txt")).unwrap()
+    );
+
+    // The first day of a year also creates the year module.
+    let created = new_day(&root, 2024, 1).unwrap();
+    assert_eq!(5, created.len());
+    assert_eq!(
+        "pub mod day1;\n",
+        read(&root.join("src/y2024/mod.rs")).unwrap()
+    );
+    assert_eq!(
+        "mod python;\npub mod y2023;\npub mod y2024;\n",
+        read(&root.join("src/lib.rs")).unwrap()
+    );
+    assert!(read(&root.join("src/registry.rs"))
+        .unwrap()
+        .contains("    2024 => y2024 {\n        1 => day1,\n    }\n"));
+
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,185 @@
+use std::time::Duration;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{
+    registry::{Generate, Solver},
+    runner, util,
+};
+
+/// Each size is run this many times and the fastest run is kept, which
+/// smooths out the noise of the small inputs.
+const RUNS: usize = 3;
+
+/// The fewest sizes a curve is fitted through.
+const MIN_POINTS: usize = 3;
+
+struct Point {
+    size: usize,
+    bytes: usize,
+    elapsed: Duration,
+}
+
+/// Times the solver on generated inputs of doubling sizes up to `max_size`,
+/// and fits `time = c * bytes^k` through the timings to estimate how the
+/// solver grows with the length of the input. The sweep stops at the first
+/// size that fails or takes longer than `timeout`.
+pub fn scale(
+    solver: &Solver,
+    generate: Generate,
+    max_size: usize,
+    seed: u64,
+    timeout: Duration,
+) -> String {
+    let mut points = Vec::<Point>::new();
+    let mut stopped = None;
+
+    let sizes = std::iter::successors(Some(1usize), |s| s.checked_mul(2));
+    for size in sizes.take_while(|&s| s <= max_size) {
+        let input = generate(&mut StdRng::seed_from_u64(seed), size);
+
+        // Most generators stop growing at some size.
+        if points.last().is_some_and(|p| p.bytes >= input.len()) {
+            continue;
+        }
+
+        let mut elapsed = Duration::MAX;
+        for _ in 0..RUNS {
+            let outcome = runner::run_with_timeout(solver, &input, timeout);
+            if let Err(err) = outcome.answer {
+                stopped = Some(format!("stopped at size {size}: {err}"));
+                break;
+            }
+            elapsed = elapsed.min(outcome.elapsed);
+        }
+        if stopped.is_some() {
+            break;
+        }
+
+        points.push(Point {
+            size,
+            bytes: input.len(),
+            elapsed,
+        });
+    }
+
+    let header = ["size", "bytes", "time"].map(|h| h.to_string());
+    let rows = points
+        .iter()
+        .map(|p| {
+            vec![
+                p.size.to_string(),
+                p.bytes.to_string(),
+                runner::format_duration(p.elapsed),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut out = util::text_table(&header, &rows);
+    out.push('\n');
+
+    if let Some(stopped) = stopped {
+        out.push_str(&format!("{stopped}\n"));
+    }
+
+    let samples = points
+        .iter()
+        .map(|p| (p.bytes as f64, p.elapsed.as_secs_f64()))
+        .collect::<Vec<_>>();
+
+    match fit(&samples) {
+        Some((exponent, r2)) => out.push_str(&format!(
+            "time grows like n^{exponent:.2} in the length of the input (r² = {r2:.2}), {}\n",
+            describe(exponent)
+        )),
+        None => out.push_str(&format!(
+            "not enough sizes to fit a curve, need at least {MIN_POINTS}\n"
+        )),
+    }
+
+    out
+}
+
+/// Fits `y = c * x^k` through the points with least squares on their
+/// logarithms. Returns the exponent `k` and how well it fits, as r².
+fn fit(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+    let logs = points
+        .iter()
+        .filter(|&&(x, y)| x > 0.0 && y > 0.0)
+        .map(|&(x, y)| (x.ln(), y.ln()))
+        .collect::<Vec<_>>();
+    if logs.len() < MIN_POINTS {
+        return None;
+    }
+
+    let n = logs.len() as f64;
+    let mean_x = logs.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = logs.iter().map(|p| p.1).sum::<f64>() / n;
+
+    let sxx = logs.iter().map(|p| (p.0 - mean_x).powi(2)).sum::<f64>();
+    let sxy = logs
+        .iter()
+        .map(|p| (p.0 - mean_x) * (p.1 - mean_y))
+        .sum::<f64>();
+    let syy = logs.iter().map(|p| (p.1 - mean_y).powi(2)).sum::<f64>();
+    if sxx == 0.0 {
+        return None;
+    }
+
+    let exponent = sxy / sxx;
+    let r2 = if syy == 0.0 {
+        1.0
+    } else {
+        sxy * sxy / (sxx * syy)
+    };
+
+    Some((exponent, r2))
+}
+
+/// Names the growth closest to the exponent.
+fn describe(exponent: f64) -> String {
+    const NAMES: [&str; 4] = ["constant", "linear", "quadratic", "cubic"];
+
+    let nearest = exponent.round();
+    if (exponent - nearest).abs() <= 0.25 && (0.0..=3.0).contains(&nearest) {
+        format!("about {}", NAMES[nearest as usize])
+    } else if (0.0..3.0).contains(&exponent) {
+        let below = exponent.floor() as usize;
+        format!("between {} and {}", NAMES[below], NAMES[below + 1])
+    } else if exponent < 0.0 {
+        "faster on larger inputs, the timings are probably noise".to_string()
+    } else {
+        "worse than cubic".to_string()
+    }
+}
+
+#[test]
+fn test_fit() {
+    let quadratic = [10.0, 20.0, 40.0, 80.0].map(|x: f64| (x, 3.0 * x * x));
+    let (exponent, r2) = fit(&quadratic).unwrap();
+    assert!((exponent - 2.0).abs() < 1e-9, "{exponent}");
+    assert!((r2 - 1.0).abs() < 1e-9, "{r2}");
+
+    assert_eq!(None, fit(&quadratic[..2]));
+    assert_eq!(None, fit(&[(10.0, 1.0), (10.0, 2.0), (10.0, 3.0)]));
+}
+
+#[test]
+fn test_describe() {
+    assert_eq!("about linear", describe(1.1));
+    assert_eq!("about quadratic", describe(1.8));
+    assert_eq!("between linear and quadratic", describe(1.5));
+    assert_eq!("worse than cubic", describe(3.6));
+}
+
+#[test]
+fn test_scale() {
+    let generate = crate::registry::generator(2023, 1).unwrap();
+    let solver = crate::registry::find(2023, 1, 1).unwrap();
+
+    let out = scale(&solver, generate, 16, 1, Duration::from_secs(10));
+
+    assert!(out.starts_with("size | bytes | time\n"), "{out}");
+    assert!(out.contains("\n16   |"), "{out}");
+    assert!(out.contains("time grows like n^"), "{out}");
+}
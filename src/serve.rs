@@ -0,0 +1,279 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    thread,
+};
+
+use itertools::Itertools;
+
+use crate::{registry, runner, util};
+
+/// Inputs are a few kilobytes, anything much larger is a mistake.
+const MAX_BODY: usize = 1 << 20;
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+/// Handles requests on the listener until it fails. Each connection gets its
+/// own thread, so a slow solver does not hold up other requests.
+///
+///    POST /solve/{day}/{part}[?year=2023]  solves the input in the body
+///    GET /days                             lists the registered solvers
+pub fn serve(listener: TcpListener) -> Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream.map_err(|err| err.to_string())?;
+
+        thread::spawn(move || {
+            if let Err(err) = handle(stream) {
+                eprintln!("{err}");
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn handle(mut stream: TcpStream) -> Result<()> {
+    let response = match read_request(&mut stream) {
+        Ok(request) => route(&request),
+        Err(response) => response,
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        response.body.len(),
+        response.body
+    )
+    .map_err(|err| err.to_string())
+}
+
+fn read_request(stream: &mut TcpStream) -> std::result::Result<Request, Response> {
+    let mut reader = BufReader::new(stream);
+    let bad_request = |message: &str| error(400, message);
+
+    let mut line = String::new();
+    reader
+        .read_line(&mut line)
+        .map_err(|err| bad_request(&err.to_string()))?;
+
+    let (method, path) = match line.split_whitespace().collect_vec().as_slice() {
+        [method, path, _version] => (method.to_string(), path.to_string()),
+        _ => return Err(bad_request("invalid request line")),
+    };
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader
+            .read_line(&mut header)
+            .map_err(|err| bad_request(&err.to_string()))?;
+
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| bad_request("invalid content length"))?;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY {
+        return Err(error(413, "the input is too large"));
+    }
+
+    let mut body = vec![0; content_length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|err| bad_request(&err.to_string()))?;
+
+    Ok(Request { method, path, body })
+}
+
+fn route(request: &Request) -> Response {
+    let (path, query) = request
+        .path
+        .split_once('?')
+        .unwrap_or((request.path.as_str(), ""));
+    let segments = path.trim_matches('/').split('/').collect_vec();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["days"]) => days(),
+        ("POST", ["solve", day, part]) => solve(day, part, query, &request.body),
+        (_, ["days"]) | (_, ["solve", _, _]) => error(405, "method not allowed"),
+        _ => error(404, "not found"),
+    }
+}
+
+fn days() -> Response {
+    let days = registry::solvers()
+        .chunk_by(|a, b| (a.year, a.day) == (b.year, b.day))
+        .map(|solvers| {
+            format!(
+                "{{\"year\":{},\"day\":{},\"parts\":[{}]}}",
+                solvers[0].year,
+                solvers[0].day,
+                solvers
+                    .iter()
+                    .filter(|s| registry::has_puzzle(s.day, s.part))
+                    .map(|s| s.part)
+                    .dedup()
+                    .join(",")
+            )
+        })
+        .join(",");
+
+    Response {
+        status: 200,
+        body: format!("[{days}]"),
+    }
+}
+
+fn solve(day: &str, part: &str, query: &str, body: &[u8]) -> Response {
+    let Some(day) = registry::parse_day(day) else {
+        return error(400, &format!("invalid day {day}"));
+    };
+    let Some(part) = registry::parse_part(part) else {
+        return error(400, &format!("invalid part {part}"));
+    };
+
+    let year = match query.split('&').find_map(|q| q.strip_prefix("year=")) {
+        Some(year) => match year.parse() {
+            Ok(year) => year,
+            Err(_) => return error(400, &format!("invalid year {year}")),
+        },
+        None => registry::latest_year(),
+    };
+
+    let Some(solver) = registry::find(year, day, part) else {
+        return error(
+            404,
+            &format!("Invalid year {year}, day {day} or part {part}"),
+        );
+    };
+
+    let Ok(input) = std::str::from_utf8(body) else {
+        return error(400, "the input is not valid UTF-8");
+    };
+
+    let outcome = runner::run(&solver, input);
+    let micros = outcome.elapsed.as_micros();
+
+    match outcome.answer {
+        Ok(answer) => Response {
+            status: 200,
+            body: format!(
+                "{{\"year\":{year},\"day\":{day},\"part\":{part},\"answer\":{},\"micros\":{micros}}}",
+                answer.to_json()
+            ),
+        },
+        Err(err) => Response {
+            status: 422,
+            body: format!(
+                "{{\"year\":{year},\"day\":{day},\"part\":{part},\"error\":{},\"micros\":{micros}}}",
+                util::json_string(&err.to_string())
+            ),
+        },
+    }
+}
+
+fn error(status: u16, message: &str) -> Response {
+    Response {
+        status,
+        body: format!("{{\"error\":{}}}", util::json_string(message)),
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        _ => "Internal Server Error",
+    }
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[cfg(test)]
+fn request(addr: std::net::SocketAddr, raw: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.write_all(raw.as_bytes()).unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn test_serve() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || serve(listener));
+
+    let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+    let response = request(
+        addr,
+        &format!(
+            "POST /solve/day1/part1?year=2023 HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{input}",
+            input.len()
+        ),
+    );
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+    assert!(response.contains("\"answer\":142,"), "{response}");
+
+    let response = request(addr, "GET /days HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+    assert!(response.contains("{\"year\":2023,\"day\":24,\"parts\":[1,2]}"));
+    assert!(response.contains("{\"year\":2023,\"day\":25,\"parts\":[1]}"));
+
+    let response = request(addr, "POST /solve/26/1 HTTP/1.1\r\n\r\n");
+    assert!(
+        response.starts_with("HTTP/1.1 404 Not Found\r\n"),
+        "{response}"
+    );
+
+    let response = request(addr, "GET /solve/1/1 HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+
+    let response = request(addr, "POST /solve/25/2 HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 422 Unprocessable Entity\r\n"));
+}
+
+#[test]
+fn test_route() {
+    let request = |method: &str, path: &str| Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        body: Vec::new(),
+    };
+
+    assert_eq!(404, route(&request("GET", "/")).status);
+    assert_eq!(400, route(&request("POST", "/solve/x/1")).status);
+    assert_eq!(400, route(&request("POST", "/solve/1/1?year=y")).status);
+    assert_eq!(404, route(&request("POST", "/solve/1/1?year=2015")).status);
+}
@@ -0,0 +1,379 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use crate::y2023;
+
+/// A puzzle that plays out step by step, such as rocks rolling on a platform or
+/// pulses travelling through a network.
+pub trait Simulation {
+    /// Advances the simulation by one step.
+    fn step(&mut self) -> Result<()>;
+
+    /// Returns true once a step would no longer change anything.
+    fn finished(&self) -> bool {
+        false
+    }
+
+    /// The part of the state that decides how the simulation goes on, so
+    /// repeated states can be found. Equal states must play out the same.
+    fn state(&self) -> State;
+
+    fn snapshot(&self) -> Snapshot;
+
+    /// Goes back to the state of a snapshot of the same simulation.
+    fn restore(&mut self, snapshot: &Snapshot);
+
+    /// Draws the state as text.
+    fn render(&self) -> String;
+
+    /// Steps until `done` holds for the state, and returns the number of steps
+    /// taken. Returns None if it does not hold within `max_steps` steps, or
+    /// before the simulation finishes.
+    fn run_until(
+        &mut self,
+        max_steps: usize,
+        mut done: impl FnMut(&Self) -> bool,
+    ) -> Result<Option<usize>>
+    where
+        Self: Sized,
+    {
+        for steps in 0..=max_steps {
+            if done(self) {
+                return Ok(Some(steps));
+            }
+            if self.finished() || steps == max_steps {
+                break;
+            }
+            self.step()?;
+        }
+
+        Ok(None)
+    }
+}
+
+/// A copy of the state of a simulation, to go back to with `restore`.
+pub struct Snapshot(Box<dyn Any>);
+
+impl Snapshot {
+    pub fn new<T: Clone + 'static>(state: &T) -> Snapshot {
+        Snapshot(Box::new(state.clone()))
+    }
+
+    /// Returns the state. Panics if the snapshot is of another type.
+    pub fn get<T: Clone + 'static>(&self) -> T {
+        self.0
+            .downcast_ref::<T>()
+            .expect("a snapshot of another simulation")
+            .clone()
+    }
+}
+
+/// A state of a simulation, as the bytes its parts feed to a hasher. The
+/// lengths of collections and the ends of strings are fed as well, so states
+/// are only equal if their parts are.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct State(Vec<u8>);
+
+impl State {
+    pub fn of<T: Hash + ?Sized>(value: &T) -> State {
+        let mut recorder = Recorder(Vec::new());
+        value.hash(&mut recorder);
+        State(recorder.0)
+    }
+
+    /// Hashes the state the same way in every run, to show it in short.
+    pub fn hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        hasher.write(&self.0);
+        hasher.finish()
+    }
+}
+
+/// A hasher that keeps the bytes fed to it instead of hashing them.
+struct Recorder(Vec<u8>);
+
+impl Hasher for Recorder {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        unreachable!("the bytes are kept, not hashed")
+    }
+}
+
+/// The states repeat from step `start` on, every `length` steps.
+#[derive(Debug, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+pub type Parse = fn(&str) -> Result<Box<dyn Simulation>>;
+
+/// The days that can be simulated step by step.
+const SIMULATIONS: &[(u32, u32, Parse)] = &[
+    (2023, 14, y2023::day14::simulation),
+    (2023, 16, y2023::day16::simulation),
+    (2023, 20, y2023::day20::simulation),
+    (2023, 21, y2023::day21::simulation),
+    (2023, 22, y2023::day22::simulation),
+];
+
+/// Returns the parser of the simulation of the day.
+pub fn find(year: u32, day: u32) -> Option<Parse> {
+    SIMULATIONS
+        .iter()
+        .find(|(y, d, _)| *y == year && *d == day)
+        .map(|&(_, _, parse)| parse)
+}
+
+/// Steps up to `steps` times, stopping early once the simulation finishes.
+/// Returns the number of steps taken.
+pub fn run(sim: &mut dyn Simulation, steps: usize) -> Result<usize> {
+    for taken in 0..steps {
+        if sim.finished() {
+            return Ok(taken);
+        }
+        sim.step()?;
+    }
+
+    Ok(steps)
+}
+
+/// Steps until a state repeats, for at most `max_steps` steps. There is no
+/// cycle if the simulation finishes first.
+pub fn find_cycle(sim: &mut dyn Simulation, max_steps: usize) -> Result<Option<Cycle>> {
+    let mut seen = HashMap::new();
+
+    for step in 0..=max_steps {
+        let state = sim.state();
+        if let Some(&start) = seen.get(&state) {
+            return Ok(Some(Cycle {
+                start,
+                length: step - start,
+            }));
+        }
+        if sim.finished() || step == max_steps {
+            break;
+        }
+
+        seen.insert(state, step);
+        sim.step()?;
+    }
+
+    Ok(None)
+}
+
+/// Moves the simulation to its state after `steps` steps. Once a state
+/// repeats, the rest of the steps are skipped over, so this also works for
+/// far more steps than could be run. It keeps a snapshot of every state until
+/// the first repetition.
+pub fn fast_forward(sim: &mut dyn Simulation, steps: usize) -> Result<()> {
+    let mut seen = HashMap::new();
+    let mut snapshots = Vec::new();
+
+    for step in 0..steps {
+        let state = sim.state();
+        if let Some(&start) = seen.get(&state) {
+            let length = step - start;
+            sim.restore(&snapshots[start + (steps - start) % length]);
+            return Ok(());
+        }
+        if sim.finished() {
+            return Ok(());
+        }
+
+        seen.insert(state, step);
+        snapshots.push(sim.snapshot());
+        sim.step()?;
+    }
+
+    Ok(())
+}
+
+/// Renders the state before the first step and after each of up to `steps`
+/// steps.
+pub fn dump(sim: &mut dyn Simulation, steps: usize) -> Result<String> {
+    let mut out = String::new();
+
+    for step in 0..=steps {
+        out.push_str(&format!(
+            "step {step}, state {:016x}:\n",
+            sim.state().hash()
+        ));
+        out.push_str(&sim.render());
+        out.push('\n');
+
+        if step == steps || sim.finished() {
+            break;
+        }
+        sim.step()?;
+    }
+
+    Ok(out)
+}
+
+/// Runs the simulation for the `simulate` command: it dumps the states, looks
+/// for a repetition, or only runs the steps and describes where it ended up.
+pub fn simulate(
+    sim: &mut dyn Simulation,
+    steps: usize,
+    dump_states: bool,
+    cycle: bool,
+) -> Result<String> {
+    if dump_states {
+        return dump(sim, steps);
+    }
+
+    if cycle {
+        return Ok(match find_cycle(sim, steps)? {
+            Some(Cycle { start, length }) => {
+                format!("the states repeat every {length} steps from step {start}\n")
+            }
+            None if sim.finished() => "the simulation finished without repeating\n".to_string(),
+            None => format!("no state repeats within {steps} steps\n"),
+        });
+    }
+
+    let taken = run(sim, steps)?;
+    let finished = if sim.finished() { ", finished" } else { "" };
+
+    Ok(format!(
+        "step {taken}, state {:016x}{finished}\n",
+        sim.state().hash()
+    ))
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+/// Counts up to `limit` and starts over at `reset`, or stops at the limit if
+/// there is no reset.
+#[cfg(test)]
+#[derive(Clone)]
+struct Counter {
+    value: usize,
+    limit: usize,
+    reset: Option<usize>,
+}
+
+#[cfg(test)]
+impl Simulation for Counter {
+    fn step(&mut self) -> Result<()> {
+        self.value = match self.reset {
+            Some(reset) if self.value == self.limit => reset,
+            _ => self.value + 1,
+        };
+        Ok(())
+    }
+
+    fn finished(&self) -> bool {
+        self.reset.is_none() && self.value == self.limit
+    }
+
+    fn state(&self) -> State {
+        State::of(&self.value)
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new(self)
+    }
+
+    fn restore(&mut self, snapshot: &Snapshot) {
+        *self = snapshot.get();
+    }
+
+    fn render(&self) -> String {
+        format!("{}\n", self.value)
+    }
+}
+
+#[cfg(test)]
+fn counter(limit: usize, reset: Option<usize>) -> Counter {
+    Counter {
+        value: 0,
+        limit,
+        reset,
+    }
+}
+
+#[test]
+fn test_run() {
+    let mut sim = counter(5, None);
+    assert_eq!(Ok(3), run(&mut sim, 3));
+    assert_eq!(Ok(2), run(&mut sim, 10));
+    assert_eq!(5, sim.value);
+
+    let mut sim = counter(5, None);
+    assert_eq!(Ok(Some(4)), sim.run_until(10, |s| s.value == 4));
+    assert_eq!(Ok(None), sim.run_until(10, |s| s.value == 6));
+    assert_eq!(Ok(None), counter(5, Some(0)).run_until(3, |s| s.value == 4));
+}
+
+#[test]
+fn test_find_cycle() {
+    let cycle = find_cycle(&mut counter(5, Some(2)), 100);
+    assert_eq!(
+        Ok(Some(Cycle {
+            start: 2,
+            length: 4
+        })),
+        cycle
+    );
+
+    assert_eq!(Ok(None), find_cycle(&mut counter(5, Some(2)), 5));
+    assert_eq!(Ok(None), find_cycle(&mut counter(5, None), 100));
+}
+
+#[test]
+fn test_fast_forward() {
+    // 0 1 2 3 4 5 2 3 4 5 ...
+    let mut sim = counter(5, Some(2));
+    fast_forward(&mut sim, 1_000_000_001).unwrap();
+    assert_eq!(5, sim.value);
+
+    let mut sim = counter(5, Some(2));
+    fast_forward(&mut sim, 4).unwrap();
+    assert_eq!(4, sim.value);
+
+    let mut sim = counter(5, None);
+    fast_forward(&mut sim, 1_000).unwrap();
+    assert_eq!(5, sim.value);
+}
+
+#[test]
+fn test_dump() {
+    let out = dump(&mut counter(1, None), 5).unwrap();
+    assert_eq!(
+        format!(
+            "step 0, state {:016x}:\n0\n\nstep 1, state {:016x}:\n1\n\n",
+            State::of(&0usize).hash(),
+            State::of(&1usize).hash()
+        ),
+        out
+    );
+}
+
+#[test]
+fn test_state() {
+    assert_eq!(State::of(&vec![1, 2]), State::of(&vec![1, 2]));
+    assert_ne!(State::of(&vec![1, 2]), State::of(&vec![2, 1]));
+    assert_ne!(State::of(&("ab", "c")), State::of(&("a", "bc")));
+    assert_ne!(
+        State::of(&(vec![1], vec![2, 3])),
+        State::of(&(vec![1, 2], vec![3]))
+    );
+
+    // The hash is that of the value itself.
+    let mut hasher = DefaultHasher::new();
+    (7usize, "rocks").hash(&mut hasher);
+    assert_eq!(hasher.finish(), State::of(&(7usize, "rocks")).hash());
+}
@@ -0,0 +1,208 @@
+use std::{cell::RefCell, fmt};
+
+use crate::util;
+
+/// Emits a structured trace record from a solver.
+///
+/// The record is only built when a trace is being captured, so it is cheap to
+/// leave the calls in place.
+///
+/// Example:
+///    trace::emit!("calibration", line = s, value = v);
+#[macro_export]
+macro_rules! emit {
+    ( $kind:expr $(, $key:ident = $value:expr)* $(,)? ) => {
+        if $crate::trace::enabled() {
+            $crate::trace::record($crate::trace::Record {
+                kind: $kind.to_string(),
+                fields: vec![$((stringify!($key).to_string(), $crate::trace::Value::from($value))),*],
+            });
+        }
+    };
+}
+
+pub(crate) use emit;
+
+thread_local! {
+    static SINK: RefCell<Option<Vec<Record>>> = const { RefCell::new(None) };
+}
+
+pub struct Record {
+    pub kind: String,
+    pub fields: Vec<(String, Value)>,
+}
+
+pub enum Value {
+    Int(i128),
+    Bool(bool),
+    Text(String),
+}
+
+/// Returns true if the records emitted on this thread are being captured.
+pub fn enabled() -> bool {
+    SINK.with(|sink| sink.borrow().is_some())
+}
+
+pub fn record(r: Record) {
+    SINK.with(|sink| {
+        if let Some(records) = sink.borrow_mut().as_mut() {
+            records.push(r);
+        }
+    })
+}
+
+/// Runs `f` and collects all records it emits.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<Record>) {
+    let previous = SINK.with(|sink| sink.replace(Some(Vec::new())));
+    let result = f();
+    let records = SINK.with(|sink| sink.replace(previous)).unwrap_or_default();
+
+    (result, records)
+}
+
+/// Renders the records as aligned tables, starting a new table whenever the
+/// kind of record changes.
+pub fn render_table(records: &[Record]) -> String {
+    let mut out = String::new();
+
+    for group in records.chunk_by(|a, b| a.kind == b.kind && a.keys().eq(b.keys())) {
+        let mut header = vec!["#".to_string()];
+        header.extend(group[0].keys().map(|k| k.to_string()));
+
+        let rows = group
+            .iter()
+            .enumerate()
+            .map(|(i, r)| {
+                let mut row = vec![(i + 1).to_string()];
+                row.extend(r.fields.iter().map(|(_, v)| v.to_string()));
+                row
+            })
+            .collect::<Vec<_>>();
+
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&format!("{}:\n", group[0].kind));
+        out.push_str(&util::text_table(&header, &rows));
+    }
+
+    out
+}
+
+/// Renders the records as JSON lines, one object per record.
+pub fn render_json(records: &[Record]) -> String {
+    let mut out = String::new();
+
+    for r in records {
+        out.push_str(&format!("{{\"record\":{}", util::json_string(&r.kind)));
+        for (key, value) in r.fields.iter() {
+            let value = match value {
+                Value::Int(v) => v.to_string(),
+                Value::Bool(v) => v.to_string(),
+                Value::Text(v) => util::json_string(v),
+            };
+            out.push_str(&format!(",{}:{}", util::json_string(key), value));
+        }
+        out.push_str("}\n");
+    }
+
+    out
+}
+
+impl Record {
+    fn keys(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|(k, _)| k.as_str())
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(v) => write!(f, "{v}"),
+            Value::Bool(v) => write!(f, "{v}"),
+            Value::Text(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+macro_rules! int_value {
+    ( $($t:ty),* ) => {
+        $(
+            impl From<$t> for Value {
+                fn from(v: $t) -> Self {
+                    Value::Int(v as i128)
+                }
+            }
+        )*
+    };
+}
+
+int_value!(i32, u32, i64, u64, usize);
+
+impl From<bool> for Value {
+    fn from(v: bool) -> Self {
+        Value::Bool(v)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(v: &str) -> Self {
+        Value::Text(v.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(v: String) -> Self {
+        Value::Text(v)
+    }
+}
+
+impl From<&String> for Value {
+    fn from(v: &String) -> Self {
+        Value::Text(v.clone())
+    }
+}
+
+#[test]
+fn test_capture() {
+    let (result, records) = capture(|| {
+        emit!("line", value = 1, valid = true);
+        emit!("line", value = 2, valid = false);
+        3
+    });
+
+    assert_eq!(3, result);
+    assert_eq!(2, records.len());
+    assert!(!enabled());
+}
+
+#[test]
+fn test_emit_without_capture() {
+    emit!("line", value = 1);
+    assert!(!enabled());
+}
+
+#[test]
+fn test_render_table() {
+    let (_, records) = capture(|| {
+        emit!("game", id = 1, valid = true);
+        emit!("game", id = 20, valid = false);
+    });
+
+    assert_eq!(
+        "game:\n# | id | valid\n- | -- | -----\n1 | 1  | true\n2 | 20 | false\n",
+        render_table(&records)
+    );
+}
+
+#[test]
+fn test_render_json() {
+    let (_, records) = capture(|| {
+        emit!("hand", cards = "32T3K", rank = 1);
+    });
+
+    assert_eq!(
+        "{\"record\":\"hand\",\"cards\":\"32T3K\",\"rank\":1}\n",
+        render_json(&records)
+    );
+}
@@ -22,3 +22,75 @@ pub fn non_empty_lines(s: &str) -> impl Iterator<Item = String> + '_ {
         .filter(|s| !s.is_empty())
         .map(|s| s.to_string())
 }
+
+/// Quotes and escapes a string so it can be used as a JSON value.
+pub fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+/// Renders rows as an aligned plain text table, with a line under the header.
+pub fn text_table(header: &[String], rows: &[Vec<String>]) -> String {
+    let mut widths = header.iter().map(|h| h.chars().count()).collect::<Vec<_>>();
+    for row in rows.iter() {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        let row = cells
+            .iter()
+            .zip(widths.iter())
+            .map(|(c, &w)| format!("{c:<w$}"))
+            .collect::<Vec<_>>()
+            .join(" | ");
+
+        format!("{}\n", row.trim_end())
+    };
+
+    let mut out = format_row(header);
+    out.push_str(&format_row(
+        &widths.iter().map(|&w| "-".repeat(w)).collect::<Vec<_>>(),
+    ));
+    for row in rows.iter() {
+        out.push_str(&format_row(row));
+    }
+
+    out
+}
+
+#[test]
+fn test_json_string() {
+    assert_eq!(r#""abc""#, json_string("abc"));
+    assert_eq!(r#""a\"b\\c\n""#, json_string("a\"b\\c\n"));
+    assert_eq!(r#""\u0001""#, json_string("\u{1}"));
+}
+
+#[test]
+fn test_text_table() {
+    let header = vec!["day".to_string(), "answer".to_string()];
+    let rows = vec![
+        vec!["1".to_string(), "142".to_string()],
+        vec!["25".to_string(), "".to_string()],
+    ];
+
+    assert_eq!(
+        "day | answer\n--- | ------\n1   | 142\n25  |\n",
+        text_table(&header, &rows)
+    );
+}
@@ -0,0 +1,246 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    answer::Answer,
+    fixtures::{self, Fixture, EXAMPLES_DIR},
+    registry,
+    runner::{self, Outcome},
+};
+
+/// The modification time and size of each watched file, `None` if it does not
+/// exist. Any difference between two snapshots triggers a run.
+#[derive(PartialEq, Eq)]
+struct Snapshot(BTreeMap<PathBuf, Option<(SystemTime, u64)>>);
+
+/// Polls the input, the known answers and the examples of a day, and runs the
+/// solvers again whenever any of them changes. Never returns unless one of the
+/// parts is not registered.
+///
+/// Only data files are watched: changes to the solver itself need a rebuild,
+/// e.g. with `cargo watch -x "run watch day5"`.
+pub fn watch(year: u32, day: u32, parts: &[u32], interval: Duration) -> Result<()> {
+    for &part in parts {
+        if registry::find(year, day, part).is_none() {
+            return Err(format!("Invalid year {year}, day {day} or part {part}"));
+        }
+    }
+
+    let paths = Paths::new(year, day);
+    let mut snapshot = None;
+    let mut previous = HashMap::new();
+    let mut runs = 0;
+
+    loop {
+        let current = paths.snapshot();
+        if snapshot.as_ref() != Some(&current) {
+            snapshot = Some(current);
+            runs += 1;
+
+            println!("--- {year} day{day}, run {runs} ---");
+            print!("{}", run_all(&paths, parts, &mut previous));
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+struct Paths {
+    input: PathBuf,
+    answers: PathBuf,
+    year: u32,
+    day: u32,
+}
+
+impl Paths {
+    fn new(year: u32, day: u32) -> Self {
+        Paths {
+            input: registry::input_path(year, day),
+            answers: registry::answers_path(year, day),
+            year,
+            day,
+        }
+    }
+
+    fn examples(&self) -> PathBuf {
+        Path::new(EXAMPLES_DIR)
+            .join(self.year.to_string())
+            .join(format!("day{}", self.day))
+    }
+
+    /// Loads the puzzle input with its known answers, if any.
+    fn input(&self) -> Result<Fixture> {
+        Ok(Fixture {
+            year: self.year,
+            day: self.day,
+            name: "input".to_string(),
+            input: fs::read_to_string(&self.input)
+                .map_err(|err| format!("{}: {err}", self.input.display()))?,
+            expected: fixtures::read_expected(&self.answers)?,
+        })
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        let mut paths = vec![self.input.clone(), self.answers.clone()];
+
+        // The directory is listed on every poll so added examples are noticed.
+        if let Ok(entries) = fs::read_dir(self.examples()) {
+            paths.extend(entries.filter_map(|e| e.ok()).map(|e| e.path()));
+        }
+
+        Snapshot(
+            paths
+                .into_iter()
+                .map(|path| {
+                    let stamp = fs::metadata(&path)
+                        .ok()
+                        .and_then(|m| Some((m.modified().ok()?, m.len())));
+                    (path, stamp)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Runs the parts on the input and on each example, and returns one line per
+/// run. `previous` holds the last answer of each run to show what changed.
+fn run_all(
+    paths: &Paths,
+    parts: &[u32],
+    previous: &mut HashMap<(u32, String), String>,
+) -> String {
+    let mut out = String::new();
+
+    let mut inputs = Vec::new();
+    match paths.input() {
+        Ok(input) => inputs.push(input),
+        Err(err) => out.push_str(&format!("{err}\n")),
+    }
+    match fixtures::load_day(Path::new(EXAMPLES_DIR), paths.year, paths.day) {
+        Ok(examples) => inputs.extend(examples),
+        Err(err) => out.push_str(&format!("{err}\n")),
+    }
+
+    for &part in parts {
+        for f in inputs.iter() {
+            let Some(solver) = registry::find(f.year, f.day, part) else {
+                continue;
+            };
+
+            let outcome = runner::run(&solver, &f.input);
+            let key = (part, f.name.clone());
+
+            out.push_str(&format_line(
+                part,
+                &f.name,
+                &outcome,
+                previous.get(&key),
+                f.expected.get(&part),
+            ));
+
+            previous.insert(key, answer_text(&outcome));
+        }
+    }
+
+    out
+}
+
+fn format_line(
+    part: u32,
+    name: &str,
+    outcome: &Outcome,
+    previous: Option<&String>,
+    expected: Option<&Answer>,
+) -> String {
+    let answer = answer_text(outcome);
+
+    let shown = match previous {
+        Some(previous) if *previous != answer => format!("{previous} -> {answer}"),
+        _ => answer,
+    };
+
+    let status = match (&outcome.answer, expected) {
+        (Ok(answer), Some(expected)) if answer == expected => "pass".to_string(),
+        (_, Some(expected)) => format!("FAIL, expected {expected}"),
+        (_, None) => String::new(),
+    };
+
+    let line = format!(
+        "part{part} {name:<10} {shown:<24} {:>8}  {status}",
+        runner::format_duration(outcome.elapsed)
+    );
+
+    format!("{}\n", line.trim_end())
+}
+
+fn answer_text(outcome: &Outcome) -> String {
+    match &outcome.answer {
+        Ok(answer) => answer.to_string(),
+        Err(err) => format!("error: {err}"),
+    }
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[test]
+fn test_format_line() {
+    let outcome = Outcome {
+        answer: Ok(142.into()),
+        elapsed: Duration::from_micros(12),
+        allocs: Default::default(),
+    };
+
+    assert_eq!(
+        "part1 sample     142                          12µs  pass\n",
+        format_line(1, "sample", &outcome, None, Some(&142.into()))
+    );
+    assert_eq!(
+        "part1 input      141 -> 142                   12µs  FAIL, expected 281\n",
+        format_line(
+            1,
+            "input",
+            &outcome,
+            Some(&"141".to_string()),
+            Some(&281.into())
+        )
+    );
+    assert_eq!(
+        "part2 input      142                          12µs\n",
+        format_line(2, "input", &outcome, Some(&"142".to_string()), None)
+    );
+}
+
+#[test]
+fn test_snapshot() {
+    let root = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+    fs::create_dir_all(&root).unwrap();
+
+    let paths = Paths {
+        input: root.join("day1.txt"),
+        answers: root.join("day1.expected"),
+        year: 2023,
+        day: 1,
+    };
+
+    let before = paths.snapshot();
+    assert!(before == paths.snapshot());
+
+    fs::write(&paths.input, "1abc2").unwrap();
+    let after = paths.snapshot();
+    assert!(before != after);
+
+    fs::write(&paths.input, "1abc2\npqr3stu8vwx").unwrap();
+    assert!(after != paths.snapshot());
+
+    fs::remove_dir_all(root).unwrap();
+}
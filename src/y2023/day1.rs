@@ -0,0 +1,105 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    trace, util,
+};
+
+pub fn part1(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input);
+
+    let process = util::compose!(find_value);
+
+    let score = lines
+        .map(|line| {
+            let value = process(line.clone())?;
+            trace::emit!("calibration", line = line, value = value);
+            Ok(value)
+        })
+        .sum::<Result<u32>>()
+        .map_err(Unsolved::Parse)?;
+
+    Ok(score.into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input);
+
+    let process = util::compose!(replace_digits, find_value);
+
+    let score = lines
+        .map(|line| {
+            let value = process(line.clone())?;
+            trace::emit!("calibration", line = line, value = value);
+            Ok(value)
+        })
+        .sum::<Result<u32>>()
+        .map_err(Unsolved::Parse)?;
+
+    Ok(score.into())
+}
+
+fn find_value(s: String) -> Result<u32> {
+    let digits = s
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .collect::<Vec<u32>>();
+
+    match (digits.first(), digits.last()) {
+        (Some(first), Some(last)) => Ok(10 * first + last),
+        _ => Err(format!("no digits in '{s}'")),
+    }
+}
+
+fn replace_digits(s: String) -> String {
+    // Using the weird "one1one" format instead of just replacing with "1" handles
+    // the edge cases like "twone", where the first digit should be "2" ("two|ne")
+    // and the last should be "1" ("tw|one").
+    //
+    // Example:
+    //    "twone" -> "2ne" -> (2, 2) => WRONG!
+    //    "twone" -> "two2twone1one" -> (2, 1) => CORRECT!
+    s.replace("one", "one1one")
+        .replace("two", "two2two")
+        .replace("three", "three3three")
+        .replace("four", "four4four")
+        .replace("five", "five5five")
+        .replace("six", "six6six")
+        .replace("seven", "seven7seven")
+        .replace("eight", "eight8eight")
+        .replace("nine", "nine9nine")
+}
+
+/// Generates lines of letters, digits and spelled out digits, each with at
+/// least one digit.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    (0..1 + size.min(1_000))
+        .map(|_| {
+            let mut line = String::new();
+            for _ in 0..rng.gen_range(1..=6) {
+                match rng.gen_range(0..3) {
+                    0 => line.push(rng.gen_range(b'a'..=b'z') as char),
+                    1 => line.push_str(WORDS.choose(rng).unwrap()),
+                    _ => line.push(rng.gen_range(b'1'..=b'9') as char),
+                }
+            }
+
+            // Part 1 needs a digit on every line.
+            let at = rng.gen_range(0..=line.len());
+            line.insert(at, rng.gen_range(b'1'..=b'9') as char);
+
+            line + "\n"
+        })
+        .collect()
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
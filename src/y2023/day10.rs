@@ -0,0 +1,418 @@
+extern crate itertools;
+extern crate num;
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::{
+    answer::{Answer, Solution, Unsolved},
+    render::{Color, Image},
+    repl::Model,
+    util,
+};
+
+pub fn part1(input: &str) -> Solution {
+    Ok(farthest_distance(&input.parse().map_err(Unsolved::Parse)?))
+}
+
+pub fn part2(input: &str) -> Solution {
+    Ok(enclosed_tiles(&input.parse().map_err(Unsolved::Parse)?))
+}
+
+fn farthest_distance(map: &Map) -> Answer {
+    let loop_size = map.iter().count();
+
+    (loop_size / 2).into()
+}
+
+fn enclosed_tiles(map: &Map) -> Answer {
+    let loop_tiles = map.iter().collect::<HashSet<_>>();
+
+    inside_tiles(map, &loop_tiles).len().into()
+}
+
+/// Draws the loop, with the tiles inside it shaded.
+pub fn render(input: &str, _part: u32) -> Result<Image> {
+    let map = input.parse::<Map>()?;
+
+    let loop_tiles = map.iter().collect::<HashSet<_>>();
+    let inside = inside_tiles(&map, &loop_tiles);
+
+    let width = map
+        .lines
+        .iter()
+        .map(|l| l.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut image = Image::new(width, map.lines.len(), Color::WHITE);
+
+    for &pos in loop_tiles.iter() {
+        image.set(pos.1 as usize, pos.0 as usize, Color::DARK);
+    }
+    for &pos in inside.iter() {
+        image.set(pos.1 as usize, pos.0 as usize, Color::GREEN);
+    }
+
+    Ok(image)
+}
+
+fn inside_tiles(map: &Map, loop_tiles: &HashSet<Pos>) -> HashSet<Pos> {
+    let mut inside_tiles = HashSet::new();
+
+    for row in 0..map.lines.len() {
+        let mut inside = false;
+        let mut first_loop_tile: Option<char> = None;
+        let width = map.lines[row].chars().count();
+
+        for col in 0..width {
+            let pos = Pos(row as i32, col as i32);
+            let c = map.values[&pos];
+
+            if loop_tiles.contains(&pos) {
+                if let Some(f) = first_loop_tile {
+                    match (f, c) {
+                        ('F', '7') | ('L', 'J') => {
+                            // We stepped off on the same side
+                            first_loop_tile = None;
+                            inside = !inside;
+                        }
+                        ('L', '7') | ('F', 'J') => {
+                            // We stepped off on the other side
+                            first_loop_tile = None;
+                        }
+                        ('F' | 'L', '-') => {
+                            // We're still walking along the loop
+                        }
+                        _ => {
+                            // We stepped of one part of the loop onto another
+                            first_loop_tile = Some(c);
+                            inside = !inside;
+                        }
+                    }
+                } else {
+                    // We stepped onto the loop
+                    first_loop_tile = Some(c);
+                    inside = !inside;
+                }
+            } else {
+                if let Some(_) = first_loop_tile {
+                    // We stepped of the loop
+                    first_loop_tile = None;
+                }
+
+                if inside {
+                    inside_tiles.insert(pos);
+                }
+            }
+        }
+    }
+
+    inside_tiles
+}
+
+pub fn model(input: &str) -> Result<Box<dyn Model>> {
+    Ok(Box::new(input.parse::<Map>()?))
+}
+
+struct Map {
+    values: HashMap<Pos, char>,
+    start: Pos,
+    lines: Vec<String>,
+}
+
+struct MapIterator<'a> {
+    m: &'a Map,
+    pos: Pos,
+    delta: Pos,
+    visited: HashSet<Pos>,
+}
+
+impl Iterator for MapIterator<'_> {
+    type Item = Pos;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.visited.contains(&self.pos) {
+            return None;
+        }
+
+        self.visited.insert(self.pos);
+
+        (self.pos, self.delta) = self.m.step(self.pos, self.delta)?;
+
+        Some(self.pos)
+    }
+}
+
+impl Map {
+    fn iter<'a>(&'a self) -> MapIterator<'a> {
+        MapIterator {
+            m: self,
+            pos: self.start,
+            delta: start_delta(&self.values[&self.start]),
+            visited: HashSet::new(),
+        }
+    }
+
+    /// Follows the pipe at `pos`, entered going `delta`, to the next tile.
+    fn step(&self, pos: Pos, delta: Pos) -> Option<(Pos, Pos)> {
+        let delta = next_delta(*self.values.get(&pos)?, delta)?;
+        let (Pos(row, col), Pos(dy, dx)) = (pos, delta);
+
+        Some((Pos(row + dy, col + dx), delta))
+    }
+
+    /// Checks that the pipes lead from the start back to it, so walking the
+    /// loop never steps off the map or into a pipe that does not connect.
+    fn check_loop(&self) -> Result<()> {
+        let mut pos = self.start;
+        let mut delta = start_delta(&self.values[&self.start]);
+
+        for _ in 0..self.values.len() {
+            let Pos(row, col) = pos;
+            (pos, delta) = self
+                .step(pos, delta)
+                .ok_or_else(|| format!("the loop is broken at row {row}, column {col}"))?;
+
+            if pos == self.start {
+                return Ok(());
+            }
+        }
+
+        Err("the loop never returns to the start".to_string())
+    }
+
+    fn start_char(&self) -> char {
+        let Pos(row, col) = self.start;
+
+        let north = self.values.get(&Pos(row - 1, col)).unwrap_or(&'.');
+        let east = self.values.get(&Pos(row, col + 1)).unwrap_or(&'.');
+        let south = self.values.get(&Pos(row + 1, col)).unwrap_or(&'.');
+        let west = self.values.get(&Pos(row, col - 1)).unwrap_or(&'.');
+
+        match (north, east, south, west) {
+            ('|' | '7' | 'F', '-' | 'J' | '7', _, _) => 'L', // North to east
+            ('|' | '7' | 'F', _, '|' | 'J' | 'L', _) => '|', // North to south
+            ('|' | '7' | 'F', _, _, '-' | 'F' | 'L') => 'J', // North to west
+            (_, '-' | 'J' | '7', '|' | 'J' | 'L', _) => 'F', // East to south
+            (_, '-' | 'J' | '7', _, '-' | 'F' | 'L') => '-', // East to west
+            (_, _, '|' | 'J' | 'L', '-' | 'F' | 'L') => '7', // South to west
+            _ => '.', // There's no valid path to this tile...
+        }
+    }
+}
+
+impl Model for Map {
+    fn part1(&self) -> Solution {
+        Ok(farthest_distance(self))
+    }
+
+    fn part2(&self) -> Solution {
+        Ok(enclosed_tiles(self))
+    }
+
+    fn views(&self) -> Vec<&'static str> {
+        vec!["map", "loop"]
+    }
+
+    fn show(&self, view: &str) -> Option<String> {
+        let loop_tiles = match view {
+            "map" => None,
+            "loop" => Some(self.iter().collect::<HashSet<_>>()),
+            _ => return None,
+        };
+
+        let mut out = String::new();
+        for row in 0..self.lines.len() {
+            for col in 0..self.lines[row].chars().count() {
+                let pos = Pos(row as i32, col as i32);
+                let c = match &loop_tiles {
+                    Some(tiles) if !tiles.contains(&pos) => '.',
+                    _ => self.values[&pos],
+                };
+                out.push(c);
+            }
+            out.push('\n');
+        }
+
+        Some(out)
+    }
+}
+
+fn start_delta(c: &char) -> Pos {
+    match c {
+        '|' => Pos(1, 0),             // Pretend we entered from north
+        'L' | 'F' => Pos(0, -1),      // Pretend we entered from east
+        '-' | 'J' | '7' => Pos(0, 1), // Pretend we entered from west
+        _ => Pos(0, 0),
+    }
+}
+
+fn next_delta(c: char, delta: Pos) -> Option<Pos> {
+    match c {
+        '|' if delta == Pos(1, 0) => Some(Pos(1, 0)),
+        '|' if delta == Pos(-1, 0) => Some(Pos(-1, 0)),
+
+        '-' if delta == Pos(0, 1) => Some(Pos(0, 1)),
+        '-' if delta == Pos(0, -1) => Some(Pos(0, -1)),
+
+        'L' if delta == Pos(1, 0) => Some(Pos(0, 1)),
+        'L' if delta == Pos(0, -1) => Some(Pos(-1, 0)),
+
+        'J' if delta == Pos(1, 0) => Some(Pos(0, -1)),
+        'J' if delta == Pos(0, 1) => Some(Pos(-1, 0)),
+
+        '7' if delta == Pos(-1, 0) => Some(Pos(0, -1)),
+        '7' if delta == Pos(0, 1) => Some(Pos(1, 0)),
+
+        'F' if delta == Pos(-1, 0) => Some(Pos(0, 1)),
+        'F' if delta == Pos(0, -1) => Some(Pos(1, 0)),
+
+        _ => None,
+    }
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let lines = util::non_empty_lines(s).collect_vec();
+
+        let mut values = HashMap::new();
+        let mut start = None;
+
+        for row in 0..lines.len() {
+            let line = &lines[row].chars().collect_vec();
+            for col in 0..line.len() {
+                let c = line[col];
+                values.insert(Pos(row as i32, col as i32), line[col]);
+
+                if c == 'S' {
+                    start = Some(Pos(row as i32, col as i32));
+                }
+            }
+        }
+
+        let mut map = Map {
+            values,
+            start: start.ok_or("there is no start tile S")?,
+            lines: lines,
+        };
+
+        // Replace the start char
+        map.values.insert(map.start, map.start_char());
+        map.check_loop()?;
+
+        Ok(map)
+    }
+}
+
+/// Generates a loop around a row of overlapping columns of random heights,
+/// like the trenches of day 18, surrounded by junk pipes that are not part of
+/// the loop.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let height = 2 + size.min(60) as i32;
+
+    // Each column overlaps the previous one, so the top and the bottom of the
+    // loop never meet.
+    let mut columns = Vec::<(i32, i32, i32)>::new();
+    for _ in 0..2 + size.min(60) / 3 {
+        let width = rng.gen_range(1..=4);
+        let (lo, hi) = loop {
+            let lo = rng.gen_range(0..height - 1);
+            let hi = rng.gen_range(lo + 1..height);
+
+            match columns.last() {
+                Some(&(_, prev_lo, prev_hi)) if lo.max(prev_lo) >= hi.min(prev_hi) => continue,
+                _ => break (lo, hi),
+            }
+        };
+        columns.push((width, lo, hi));
+    }
+
+    let mut x = 0;
+    let mut corners = vec![Pos(columns[0].1, 0)];
+    for &(width, _, hi) in columns.iter() {
+        corners.push(Pos(hi, x));
+        x += width;
+        corners.push(Pos(hi, x));
+    }
+    for &(width, lo, _) in columns.iter().rev() {
+        corners.push(Pos(lo, x));
+        x -= width;
+        corners.push(Pos(lo, x));
+    }
+
+    // Walk the corners one tile at a time, the last tile is the first one.
+    let mut tiles = vec![corners[0]];
+    for (a, b) in corners.iter().tuple_windows() {
+        let delta = Pos((b.0 - a.0).signum(), (b.1 - a.1).signum());
+        let mut pos = *a;
+        while pos != *b {
+            pos = Pos(pos.0 + delta.0, pos.1 + delta.1);
+            tiles.push(pos);
+        }
+    }
+    tiles.pop();
+
+    let width = columns.iter().map(|c| c.0).sum::<i32>();
+    let (rows, cols) = (height as usize + 2, width as usize + 3);
+    let mut grid = (0..rows)
+        .map(|_| {
+            (0..cols)
+                .map(|_| {
+                    if rng.gen_bool(0.3) {
+                        *b"|-LJ7F".choose(rng).unwrap()
+                    } else {
+                        b'.'
+                    }
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+
+    let n = tiles.len();
+    for i in 0..n {
+        let (prev, pos, next) = (tiles[(i + n - 1) % n], tiles[i], tiles[(i + 1) % n]);
+        let sides = [prev, next].map(|p| Pos(p.0 - pos.0, p.1 - pos.1));
+
+        let c = match sides {
+            [Pos(-1, 0), Pos(1, 0)] | [Pos(1, 0), Pos(-1, 0)] => b'|',
+            [Pos(0, -1), Pos(0, 1)] | [Pos(0, 1), Pos(0, -1)] => b'-',
+            [Pos(-1, 0), Pos(0, 1)] | [Pos(0, 1), Pos(-1, 0)] => b'L',
+            [Pos(-1, 0), Pos(0, -1)] | [Pos(0, -1), Pos(-1, 0)] => b'J',
+            [Pos(1, 0), Pos(0, -1)] | [Pos(0, -1), Pos(1, 0)] => b'7',
+            _ => b'F',
+        };
+        grid[(pos.0 + 1) as usize][(pos.1 + 1) as usize] = c;
+    }
+
+    // The pipe under the start is worked out from its neighbours, so only the
+    // loop may connect to it.
+    let start = tiles[rng.gen_range(0..n)];
+    for (dy, dx) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+        let pos = Pos(start.0 + dy, start.1 + dx);
+        if !tiles.contains(&pos) {
+            grid[(pos.0 + 1) as usize][(pos.1 + 1) as usize] = b'.';
+        }
+    }
+    grid[(start.0 + 1) as usize][(start.1 + 1) as usize] = b'S';
+
+    grid.into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+struct Pos(i32, i32);
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
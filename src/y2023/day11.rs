@@ -4,11 +4,15 @@ extern crate num;
 use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
 
-use crate::util;
+use crate::{
+    answer::{Solution, Unsolved},
+    params, util,
+};
 
-pub fn part1(input: &str) -> Result<String> {
-    let map = Map::parse(input, 1);
+pub fn part1(input: &str) -> Solution {
+    let map = Map::parse(input, 2);
 
     let mut distances = HashMap::<(Pos, Pos), usize>::new();
 
@@ -32,18 +36,108 @@ pub fn part1(input: &str) -> Result<String> {
         }
     }
 
-    Ok(distances.values().sum::<usize>().to_string())
+    Ok(distances.values().sum::<usize>().into())
 }
 
-#[test]
-fn test_part1() {
-    assert_eq!("374", part1(TEST_INPUT).unwrap());
+pub fn part2(input: &str) -> Solution {
+    let factor = params::get("expansion", 1_000_000)?;
+    let map = Map::parse(input, factor);
+
+    Ok(map.distances().values().sum::<usize>().into())
 }
 
-pub fn part2(input: &str) -> Result<String> {
-    let map = Map::parse(input, 1_000_000);
+/// Inserts the empty rows and columns into the image before measuring the
+/// distances between the galaxies.
+pub fn part1_brute_force(input: &str) -> Solution {
+    let image = util::non_empty_lines(input)
+        .map(|l| l.chars().collect_vec())
+        .collect_vec();
+    let width = image.first().map_or(0, |row| row.len());
+    if image.iter().any(|row| row.len() != width) {
+        let err = "the rows have different lengths".to_string();
+        return Err(Unsolved::Parse(err));
+    }
+
+    let empty_cols = (0..width)
+        .filter(|&j| image.iter().all(|row| row[j] != '#'))
+        .collect::<HashSet<_>>();
+
+    let mut expanded = Vec::new();
+    for row in image.iter() {
+        let row = row
+            .iter()
+            .enumerate()
+            .flat_map(|(j, &c)| vec![c; if empty_cols.contains(&j) { 2 } else { 1 }])
+            .collect_vec();
+
+        if !row.contains(&'#') {
+            expanded.push(row.clone());
+        }
+        expanded.push(row);
+    }
+
+    let galaxies = expanded
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| row.iter().positions(|&c| c == '#').map(move |j| (i, j)))
+        .collect_vec();
 
-    Ok(map.distances().values().sum::<usize>().to_string())
+    let sum = galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1))
+        .sum::<usize>();
+
+    Ok(sum.into())
+}
+
+/// Counts the empty rows and columns between each pair of galaxies, instead of
+/// shifting the galaxies.
+pub fn part2_brute_force(input: &str) -> Solution {
+    let factor = params::get("expansion", 1_000_000)?;
+
+    let image = util::non_empty_lines(input)
+        .map(|l| l.chars().collect_vec())
+        .collect_vec();
+    let galaxies = image
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| row.iter().positions(|&c| c == '#').map(move |j| (i, j)))
+        .collect_vec();
+
+    let empty_between = |a: usize, b: usize, has_galaxy: &dyn Fn(usize) -> bool| {
+        (a.min(b)..a.max(b)).filter(|&x| !has_galaxy(x)).count()
+    };
+    let row_has_galaxy = |i: usize| galaxies.iter().any(|g| g.0 == i);
+    let col_has_galaxy = |j: usize| galaxies.iter().any(|g| g.1 == j);
+
+    let sum = galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| {
+            let empty = empty_between(a.0, b.0, &row_has_galaxy)
+                + empty_between(a.1, b.1, &col_has_galaxy);
+
+            a.0.abs_diff(b.0) + a.1.abs_diff(b.1) + empty * (factor - 1)
+        })
+        .sum::<usize>();
+
+    Ok(sum.into())
+}
+
+/// Generates a square image with a few galaxies, which leaves some rows and
+/// columns empty.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = 3 + size.min(200) / 2;
+
+    (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| if rng.gen_bool(0.1) { '#' } else { '.' })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
 }
 
 #[test]
@@ -59,18 +153,7 @@ fn test_part2_2() {
 }
 
 #[cfg(test)]
-const TEST_INPUT: &str = "
-...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....
-";
+const TEST_INPUT: &str = include_str!("../../examples/2023/day11/sample.txt");
 
 struct Map {
     galaxies: HashSet<Pos>,
@@ -156,10 +239,3 @@ impl Map {
 
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
 struct Pos(usize, usize);
-
-// -------------------------------------
-
-type Error = String;
-type Result<T> = std::result::Result<T, Error>;
-
-// -------------------------------------
@@ -0,0 +1,205 @@
+extern crate itertools;
+extern crate num;
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    util,
+};
+
+pub fn part1(input: &str) -> Solution {
+    let mut cache = HashMap::new();
+
+    let score = parse(input)
+        .map_err(Unsolved::Parse)?
+        .into_iter()
+        .map(|(springs, groups)| {
+            count_alternatives(&springs, &groups, &mut cache).ok_or_else(too_many)
+        })
+        .sum::<Result<usize>>()?;
+
+    Ok(score.into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let mut cache = HashMap::new();
+
+    let score = parse(input)
+        .map_err(Unsolved::Parse)?
+        .into_iter()
+        .map(|(springs, groups)| {
+            let springs = [springs.as_str()].repeat(5).join("?");
+            let groups = groups.repeat(5);
+            count_alternatives(&springs, &groups, &mut cache).ok_or_else(too_many)
+        })
+        .sum::<Result<usize>>()?;
+
+    Ok(score.into())
+}
+
+/// Returns the springs of each row with the sizes of its groups of damaged
+/// springs.
+pub fn parse(input: &str) -> Result<Vec<(String, Vec<usize>)>> {
+    util::non_empty_lines(input)
+        .map(|l| parse_row(&l).map(|(springs, groups)| (springs.to_string(), groups)))
+        .collect()
+}
+
+fn parse_row(s: &str) -> Result<(&str, Vec<usize>)> {
+    let invalid = || format!("invalid row of springs: {s}");
+
+    let (springs, groups) = s.split_once(" ").ok_or_else(invalid)?;
+    if !is_springs(springs) {
+        return Err(invalid());
+    }
+    let groups = groups
+        .split(",")
+        .map(|g| g.parse::<usize>().map_err(|_| invalid()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((springs, groups))
+}
+
+/// Whether the springs are only made of '.', '#' and '?'.
+pub fn is_springs(springs: &str) -> bool {
+    springs.chars().all(|c| ".#?".contains(c))
+}
+
+fn too_many() -> Error {
+    "there are too many arrangements to count".to_string()
+}
+
+/// Counts the arrangements of damaged springs that match the groups, or None
+/// if there are too many to count.
+pub fn count_alternatives(
+    springs: &str,
+    groups: &[usize],
+    cache: &mut HashMap<(String, Vec<usize>), usize>,
+) -> Option<usize> {
+    if springs.is_empty() {
+        // We're out of springs so there should be no more groups left
+        if groups.is_empty() {
+            return Some(1);
+        } else {
+            return Some(0);
+        }
+    }
+
+    if groups.is_empty() {
+        // We're out of groups, so there should be no more _damaged_ springs left
+        if springs.contains('#') {
+            return Some(0);
+        } else {
+            return Some(1);
+        }
+    }
+
+    let cache_key = (springs.to_string(), groups.to_vec());
+
+    // Check if there's already a result for this setup in the cache
+    if let Some(&result) = cache.get(&cache_key) {
+        return Some(result);
+    }
+
+    let mut result: usize = 0;
+    if springs.starts_with(".") || springs.starts_with("?") {
+        // Assume that the spring was undamaged and count the number of valid
+        // alternatives.
+        result = result.checked_add(count_alternatives(&springs[1..], groups, cache)?)?;
+    }
+
+    if springs.starts_with("#") || springs.starts_with("?") {
+        // Assume that the spring was damaged and count the number of valid
+        // alternatives.
+
+        // Check that there are enough springs left and the upcoming subsection
+        // does not contain any undamaged springs.
+        if groups[0] <= springs.len() && !(&springs[..groups[0]]).contains(".") {
+            if groups[0] == springs.len() {
+                // The group consumes the remaining string, do a recursive call
+                // to check the end condition.
+                result = result.checked_add(count_alternatives("", &groups[1..], cache)?)?;
+            } else if springs.chars().nth(groups[0]).unwrap() != '#' {
+                // The group successfully matches the start of the string.
+                // Consume and check the remainder.
+                let rest = count_alternatives(&springs[groups[0] + 1..], &groups[1..], cache)?;
+                result = result.checked_add(rest)?;
+            }
+        }
+    }
+
+    // Update the cache with the result so we do not have to calculate it again
+    cache.insert(cache_key, result);
+
+    Some(result)
+}
+
+#[test]
+fn test_count_alternatives() {
+    assert_eq!(
+        Some(1),
+        count_alternatives("???.###", &[1, 1, 3], &mut HashMap::new())
+    );
+    assert_eq!(
+        Some(4),
+        count_alternatives(".??..??...?##.", &[1, 1, 3], &mut HashMap::new())
+    );
+    assert_eq!(
+        Some(1),
+        count_alternatives("?#?#?#?#?#?#?#?", &[1, 3, 1, 6], &mut HashMap::new())
+    );
+    assert_eq!(
+        Some(1),
+        count_alternatives("????.#...#...", &[4, 1, 1], &mut HashMap::new())
+    );
+    assert_eq!(
+        Some(4),
+        count_alternatives("????.######..#####.", &[1, 6, 5], &mut HashMap::new())
+    );
+    assert_eq!(
+        Some(10),
+        count_alternatives("?###????????", &[3, 2, 1], &mut HashMap::new())
+    );
+}
+
+/// Generates rows of springs with at least one damaged spring, and hides
+/// some of them behind a '?' after counting the groups.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..1 + size.min(1_000))
+        .map(|_| {
+            let len = rng.gen_range(3..=20);
+            let mut springs = (0..len)
+                .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+                .collect_vec();
+            let at = rng.gen_range(0..len);
+            springs[at] = '#';
+
+            let groups = springs
+                .iter()
+                .collect::<String>()
+                .split('.')
+                .filter(|g| !g.is_empty())
+                .map(|g| g.len())
+                .join(",");
+
+            for c in springs.iter_mut() {
+                if rng.gen_bool(0.4) {
+                    *c = '?';
+                }
+            }
+
+            format!("{} {groups}\n", springs.iter().collect::<String>())
+        })
+        .collect()
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
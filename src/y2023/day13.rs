@@ -2,15 +2,16 @@ extern crate itertools;
 extern crate num;
 
 use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
 
-use crate::util;
+use crate::{answer::Solution, trace, util};
 
-pub fn part1(input: &str) -> Result<String> {
+pub fn part1(input: &str) -> Solution {
     let patterns = input.split("\n\n");
 
     let mut score = 0;
 
-    for p in patterns {
+    for (i, p) in patterns.enumerate() {
         let lines = util::non_empty_lines(p)
             .map(|l| l.chars().collect())
             .collect_vec();
@@ -18,26 +19,26 @@ pub fn part1(input: &str) -> Result<String> {
         let v_count = find_reflection(&lines, 99999999);
         let h_count = find_reflection(&transpose(&lines), 99999999);
 
+        trace::emit!(
+            "pattern",
+            index = i + 1,
+            rows_above = v_count,
+            columns_left = h_count,
+            score = h_count + 100 * v_count,
+        );
+
         score += h_count + 100 * v_count;
     }
 
-    Ok(score.to_string())
+    Ok(score.into())
 }
 
-#[test]
-fn test_part1() {
-    assert_eq!("405", part1(TEST_INPUT).unwrap());
-}
-
-pub fn part2(input: &str) -> Result<String> {
+pub fn part2(input: &str) -> Solution {
     let patterns = input.split("\n\n");
 
     let mut score = 0;
 
-    for p in patterns {
-        println!("{p}");
-        println!();
-
+    for (i, p) in patterns.enumerate() {
         let mut lines = util::non_empty_lines(p)
             .map(|l| l.chars().collect())
             .collect_vec();
@@ -46,44 +47,39 @@ pub fn part2(input: &str) -> Result<String> {
         let h_b = find_reflection_with_smudge(&mut lines, h_a);
 
         if h_b != 0 {
+            trace::emit!(
+                "pattern",
+                index = i + 1,
+                rows_above = h_b,
+                columns_left = 0,
+                score = 100 * h_b,
+            );
+
             score += 100 * h_b;
         } else {
             let v_a = find_reflection(&mut transpose(&lines), 99999999);
             let v_b = find_reflection_with_smudge(&mut transpose(&lines), v_a);
 
-            assert!(v_b != 0);
+            if v_b == 0 {
+                let err = format!("pattern {} has no reflection with a smudge", i + 1);
+                return Err(err.into());
+            }
+
+            trace::emit!(
+                "pattern",
+                index = i + 1,
+                rows_above = 0,
+                columns_left = v_b,
+                score = v_b,
+            );
 
             score += v_b;
         };
     }
 
-    Ok(score.to_string())
+    Ok(score.into())
 }
 
-#[test]
-fn test_part2() {
-    assert_eq!("400", part2(TEST_INPUT).unwrap());
-}
-
-#[cfg(test)]
-const TEST_INPUT: &str = "
-#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#
-";
-
 fn find_reflection_with_smudge(lines: &mut [Vec<char>], ignore: usize) -> usize {
     for row in 0..lines.len() {
         for col in 0..lines[row].len() {
@@ -184,6 +180,97 @@ fn test_find_reflection() {
         )
     );
 }
+/// Generates patterns with a row reflection that also reflect along a column
+/// once their smudge is cleaned. Patterns with more than one reflection, or
+/// with more than one way to clean a smudge, are thrown away.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut patterns = Vec::new();
+
+    while patterns.len() < 1 + size.min(100) {
+        let (height, width) = (rng.gen_range(3..=17), rng.gen_range(2..=17));
+        let row = rng.gen_range(height / 2 + 1..height);
+        let column = rng.gen_range(1..width);
+
+        let mut lines = (0..height)
+            .map(|_| {
+                let mut line = (0..width)
+                    .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+                    .collect_vec();
+                for k in 0..column.min(width - column) {
+                    line[column + k] = line[column - 1 - k];
+                }
+                line
+            })
+            .collect_vec();
+        for k in 0..row.min(height - row) {
+            lines[row + k] = lines[row - 1 - k].clone();
+        }
+
+        // The rows above the mirrored ones can hold the smudge.
+        let smudge_row = rng.gen_range(0..2 * row - height);
+        let reach = column.min(width - column);
+        let smudge_column = rng.gen_range(column - reach..column + reach);
+        flip(&mut lines[smudge_row][smudge_column]);
+
+        if rng.gen_bool(0.5) {
+            lines.reverse();
+        }
+        if rng.gen_bool(0.5) {
+            lines = transpose(&lines);
+        }
+
+        if reflections(&lines).len() == 1 && smudged_reflections(&mut lines).len() == 1 {
+            patterns.push(
+                lines
+                    .iter()
+                    .map(|l| l.iter().collect::<String>() + "\n")
+                    .join(""),
+            );
+        }
+    }
+
+    patterns.join("\n")
+}
+
+/// Returns the reflections of the pattern, as the rows above times 100 or the
+/// columns to the left.
+fn reflections(lines: &[Vec<char>]) -> Vec<usize> {
+    let mirrors = |lines: &[Vec<char>]| {
+        (1..lines.len())
+            .filter(|&i| (0..i.min(lines.len() - i)).all(|k| lines[i - 1 - k] == lines[i + k]))
+            .collect_vec()
+    };
+
+    mirrors(lines)
+        .into_iter()
+        .map(|r| 100 * r)
+        .chain(mirrors(&transpose(lines)))
+        .collect()
+}
+
+/// Returns the new reflections found by cleaning any one smudge.
+fn smudged_reflections(lines: &mut [Vec<char>]) -> Vec<usize> {
+    let original = reflections(lines);
+    let mut found = Vec::new();
+
+    for row in 0..lines.len() {
+        for col in 0..lines[row].len() {
+            flip(&mut lines[row][col]);
+            found.extend(
+                reflections(lines)
+                    .into_iter()
+                    .filter(|r| !original.contains(r)),
+            );
+            flip(&mut lines[row][col]);
+        }
+    }
+
+    found.into_iter().unique().collect()
+}
+
+fn flip(c: &mut char) {
+    *c = if *c == '#' { '.' } else { '#' };
+}
 
 fn transpose(v: &[Vec<char>]) -> Vec<Vec<char>> {
     let mut transposed = Vec::new();
@@ -200,10 +287,3 @@ fn transpose(v: &[Vec<char>]) -> Vec<Vec<char>> {
 
     transposed
 }
-
-// -------------------------------------
-
-type Error = String;
-type Result<T> = std::result::Result<T, Error>;
-
-// -------------------------------------
@@ -0,0 +1,339 @@
+extern crate itertools;
+extern crate num;
+
+use std::fmt;
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    answer::{Answer, Solution, Unsolved},
+    params,
+    render::{Color, Image},
+    repl::Model,
+    simulation::{self, Simulation, Snapshot, State},
+    util,
+};
+
+pub fn part1(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input).collect_vec();
+
+    Ok(tilted_load(
+        &Platform::from(&lines).map_err(Unsolved::Parse)?,
+    ))
+}
+
+pub fn part2(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input).collect_vec();
+
+    spun_load(&Platform::from(&lines).map_err(Unsolved::Parse)?)
+}
+
+fn tilted_load(platform: &Platform) -> Answer {
+    let mut platform = platform.clone();
+    platform.tilt_north();
+
+    platform.northern_load().into()
+}
+
+fn spun_load(platform: &Platform) -> Solution {
+    let cycles = params::get("cycles", 1_000_000_000)?;
+
+    // The board starts repeating after a while, so the cycles after the
+    // first repetition can be skipped.
+    let mut platform = platform.clone();
+    simulation::fast_forward(&mut platform, cycles)?;
+
+    Ok(platform.northern_load().into())
+}
+
+/// Draws the platform once the rocks have rolled: tilted north for part1, and
+/// after the spin cycles for part2.
+pub fn render(input: &str, part: u32) -> Result<Image> {
+    let lines = util::non_empty_lines(input).collect_vec();
+
+    let mut platform = Platform::from(&lines)?;
+    if part == 1 {
+        platform.tilt_north();
+    } else {
+        simulation::fast_forward(&mut platform, params::get("cycles", 1_000_000_000)?)?;
+    }
+
+    let mut image = Image::new(platform.width, platform.height, Color::WHITE);
+    for (y, row) in platform.board.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            match c {
+                'O' => image.set(x, y, Color::YELLOW),
+                '#' => image.set(x, y, Color::DARK),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(image)
+}
+
+pub fn model(input: &str) -> Result<Box<dyn Model>> {
+    let lines = util::non_empty_lines(input).collect_vec();
+
+    Ok(Box::new(Platform::from(&lines)?))
+}
+
+/// Each step is a spin cycle of tilting north, west, south and east.
+pub fn simulation(input: &str) -> Result<Box<dyn Simulation>> {
+    let lines = util::non_empty_lines(input).collect_vec();
+
+    Ok(Box::new(Platform::from(&lines)?))
+}
+
+#[derive(Clone)]
+struct Platform {
+    width: usize,
+    height: usize,
+    board: Vec<Vec<char>>,
+}
+
+impl Platform {
+    fn from(input: &[String]) -> Result<Platform> {
+        let mut board = Vec::new();
+        for (i, row) in input.iter().enumerate() {
+            for c in row.chars() {
+                if i >= board.len() {
+                    board.push(Vec::new());
+                }
+
+                board[i].push(c);
+            }
+        }
+
+        let width = board.first().ok_or("the platform is empty")?.len();
+        if board.iter().any(|row| row.len() != width) {
+            return Err("the rows of the platform differ in width".to_string());
+        }
+
+        Ok(Platform {
+            width,
+            height: board.len(),
+            board: board,
+        })
+    }
+
+    fn tilt_north(&mut self) {
+        for row in 1..self.height {
+            for col in 0..self.width {
+                if self.board[row][col] == 'O' {
+                    self.roll_north(row, col);
+                }
+            }
+        }
+    }
+
+    fn tilt_east(&mut self) {
+        for col in (0..self.width - 1).rev() {
+            for row in 0..self.height {
+                if self.board[row][col] == 'O' {
+                    self.roll_east(row, col);
+                }
+            }
+        }
+    }
+
+    fn tilt_south(&mut self) {
+        for row in (0..self.height - 1).rev() {
+            for col in 0..self.width {
+                if self.board[row][col] == 'O' {
+                    self.roll_south(row, col);
+                }
+            }
+        }
+    }
+
+    fn tilt_west(&mut self) {
+        for col in 1..self.width {
+            for row in 0..self.height {
+                if self.board[row][col] == 'O' {
+                    self.roll_west(row, col);
+                }
+            }
+        }
+    }
+
+    fn roll_north(&mut self, row: usize, col: usize) {
+        let mut stop = row;
+
+        for i in (0..row).rev() {
+            let c = self.board[i][col];
+            if c == '#' || c == 'O' {
+                break;
+            }
+
+            stop = i;
+        }
+
+        if stop == row {
+            return;
+        }
+
+        self.board[row][col] = '.';
+        self.board[stop][col] = 'O';
+    }
+
+    fn roll_east(&mut self, row: usize, col: usize) {
+        let mut stop = col;
+
+        for j in col + 1..self.width {
+            let c = self.board[row][j];
+            if c == '#' || c == 'O' {
+                break;
+            }
+
+            stop = j;
+        }
+
+        self.board[row][col] = '.';
+        self.board[row][stop] = 'O';
+    }
+
+    fn roll_south(&mut self, row: usize, col: usize) {
+        let mut stop = row;
+
+        for i in row + 1..self.height {
+            let c = self.board[i][col];
+            if c == '#' || c == 'O' {
+                break;
+            }
+
+            stop = i;
+        }
+
+        self.board[row][col] = '.';
+        self.board[stop][col] = 'O';
+    }
+
+    fn roll_west(&mut self, row: usize, col: usize) {
+        let mut stop = col;
+
+        for j in (0..col).rev() {
+            let c = self.board[row][j];
+            if c == '#' || c == 'O' {
+                break;
+            }
+
+            stop = j;
+        }
+
+        self.board[row][col] = '.';
+        self.board[row][stop] = 'O';
+    }
+
+    fn northern_load(&self) -> usize {
+        let mut load = 0;
+
+        for (i, row) in self.board.iter().enumerate() {
+            for &c in row {
+                if c == 'O' {
+                    load += self.height - i;
+                }
+            }
+        }
+
+        load
+    }
+}
+
+impl Simulation for Platform {
+    fn step(&mut self) -> Result<()> {
+        self.tilt_north();
+        self.tilt_west();
+        self.tilt_south();
+        self.tilt_east();
+
+        Ok(())
+    }
+
+    fn state(&self) -> State {
+        State::of(&self.board)
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new(self)
+    }
+
+    fn restore(&mut self, snapshot: &Snapshot) {
+        *self = snapshot.get();
+    }
+
+    fn render(&self) -> String {
+        format!("{self}load: {}\n", self.northern_load())
+    }
+}
+
+impl Model for Platform {
+    fn part1(&self) -> Solution {
+        Ok(tilted_load(self))
+    }
+
+    fn part2(&self) -> Solution {
+        spun_load(self)
+    }
+
+    fn views(&self) -> Vec<&'static str> {
+        vec!["board", "tilted"]
+    }
+
+    fn show(&self, view: &str) -> Option<String> {
+        match view {
+            "board" => Some(self.to_string()),
+            "tilted" => {
+                let mut tilted = self.clone();
+                tilted.tilt_north();
+
+                Some(format!("{tilted}load: {}\n", tilted.northern_load()))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.board.iter() {
+            for c in row {
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Generates a square platform of rounded rocks, cube rocks and empty space,
+/// with at least one rounded rock.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = 3 + size.min(100);
+
+    let mut rows = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| match rng.gen_range(0..10) {
+                    0..=1 => 'O',
+                    2 => '#',
+                    _ => '.',
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+    rows[rng.gen_range(0..side)][rng.gen_range(0..side)] = 'O';
+
+    rows.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
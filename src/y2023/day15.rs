@@ -1,20 +1,19 @@
 extern crate itertools;
 extern crate num;
 
-pub fn part1(input: &str) -> Result<String> {
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::answer::{Solution, Unsolved};
+
+pub fn part1(input: &str) -> Solution {
     let steps = input.split(",").map(|s| s.trim());
 
     let score: usize = steps.map(|s| hash(s)).sum();
 
-    Ok(score.to_string())
-}
-
-#[test]
-fn test_part1() {
-    assert_eq!("1320", part1(TEST_INPUT).unwrap());
+    Ok(score.into())
 }
 
-pub fn part2(input: &str) -> Result<String> {
+pub fn part2(input: &str) -> Solution {
     let mut boxes: Vec<Vec<Lens>> = Vec::new();
     for _ in 0..256 {
         boxes.push(Vec::new());
@@ -24,7 +23,9 @@ pub fn part2(input: &str) -> Result<String> {
 
     for s in steps {
         if s.contains("-") {
-            let label = s.strip_suffix("-").unwrap();
+            let label = s
+                .strip_suffix("-")
+                .ok_or_else(|| Unsolved::Parse(format!("invalid step: {s}")))?;
 
             let box_nbr = hash(label);
             let mut idx = None;
@@ -53,7 +54,10 @@ pub fn part2(input: &str) -> Result<String> {
 
             let lens = Lens {
                 label: label.to_string(),
-                focal_length: focal_len.parse().unwrap(),
+                focal_length: focal_len
+                    .parse::<u32>()
+                    .map_err(|_| Unsolved::Parse(format!("invalid focal length: {s}")))?
+                    as usize,
             };
 
             if let Some(i) = idx {
@@ -71,26 +75,17 @@ pub fn part2(input: &str) -> Result<String> {
         }
     }
 
-    Ok(score.to_string())
-}
-
-#[test]
-fn test_part2() {
-    assert_eq!("145", part2(TEST_INPUT).unwrap());
+    Ok(score.into())
 }
 
-#[cfg(test)]
-const TEST_INPUT: &str = "
-rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
-";
-
 fn hash(s: &str) -> usize {
     let mut hash: usize = 0;
     for c in s.chars() {
         hash += c as usize;
         hash *= 17;
+        hash %= 256;
     }
-    return hash % 256;
+    return hash;
 }
 
 struct Lens {
@@ -98,9 +93,27 @@ struct Lens {
     focal_length: usize,
 }
 
-// -------------------------------------
-
-type Error = String;
-type Result<T> = std::result::Result<T, Error>;
+/// Generates a sequence of steps on a small set of labels, so lenses are
+/// replaced and removed as well as added.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let labels = (0..1 + size.min(1_000) / 4)
+        .map(|_| {
+            (0..rng.gen_range(2..=6))
+                .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    let steps = (0..1 + size.min(4_000))
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            if rng.gen_bool(0.3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.gen_range(1..=9))
+            }
+        })
+        .collect::<Vec<_>>();
 
-// -------------------------------------
+    steps.join(",") + "\n"
+}
@@ -0,0 +1,306 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    progress,
+    render::{Color, Image},
+    simulation::{self, Simulation, Snapshot, State},
+    util,
+};
+
+extern crate itertools;
+extern crate num;
+
+pub fn part1(input: &str) -> Solution {
+    let tiles = parse_tiles(input).map_err(Unsolved::Parse)?;
+
+    Ok(energize(&tiles, Pos(0, 0), Direction::Right)?.into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let tiles = parse_tiles(input).map_err(Unsolved::Parse)?;
+    let (_, score) = best_entry(&tiles)?;
+
+    Ok(score.into())
+}
+
+/// Returns where a beam should enter to energize the most tiles, and how many
+/// it energizes.
+fn best_entry(tiles: &[Vec<Tile>]) -> Result<((Pos, Direction), usize)> {
+    let (height, width) = (tiles.len() as i32, tiles[0].len() as i32);
+
+    let mut entries = Vec::new();
+
+    for row in 0..height {
+        entries.push((Pos(row, 0), Direction::Right));
+        entries.push((Pos(row, width - 1), Direction::Left));
+    }
+
+    for col in 0..width {
+        entries.push((Pos(0, col), Direction::Down));
+        entries.push((Pos(height - 1, col), Direction::Up));
+    }
+
+    progress::set_total(entries.len());
+
+    let mut best = (entries[0], 0);
+    for (pos, direction) in entries {
+        let score = energize(tiles, pos, direction)?;
+        progress::inc();
+        if score > best.1 {
+            best = ((pos, direction), score);
+        }
+    }
+
+    Ok(best)
+}
+
+/// Draws the energized tiles of the beam entering at the top left for part1,
+/// and of the beam that energizes the most tiles for part2.
+pub fn render(input: &str, part: u32) -> Result<Image> {
+    let tiles = parse_tiles(input)?;
+    let (pos, direction) = match part {
+        1 => (Pos(0, 0), Direction::Right),
+        _ => best_entry(&tiles)?.0,
+    };
+
+    let mut beams = Beams::new(tiles, pos, direction);
+    simulation::run(&mut beams, usize::MAX)?;
+
+    let mut image = Image::new(beams.tiles[0].len(), beams.tiles.len(), Color::WHITE);
+    for (y, row) in beams.tiles.iter().enumerate() {
+        for (x, t) in row.iter().enumerate() {
+            if t.c != '.' {
+                image.set(x, y, Color::DARK);
+            } else if !t.visited.is_empty() {
+                image.set(x, y, Color::YELLOW);
+            }
+        }
+    }
+
+    Ok(image)
+}
+
+/// Each step moves the beams one tile further, until they have all left the
+/// contraption or run into tiles they already passed in the same direction.
+pub fn simulation(input: &str) -> Result<Box<dyn Simulation>> {
+    let tiles = parse_tiles(input)?;
+
+    Ok(Box::new(Beams::new(tiles, Pos(0, 0), Direction::Right)))
+}
+
+/// Returns the number of tiles a beam entering at `pos` passes through.
+fn energize(tiles: &[Vec<Tile>], pos: Pos, direction: Direction) -> Result<usize> {
+    let mut beams = Beams::new(tiles.to_vec(), pos, direction);
+    simulation::run(&mut beams, usize::MAX)?;
+
+    Ok(beams.energized())
+}
+
+fn parse_tiles(input: &str) -> Result<Vec<Vec<Tile>>> {
+    let tiles = util::non_empty_lines(input)
+        .map(|l| {
+            l.chars()
+                .map(|c| Tile {
+                    visited: HashSet::new(),
+                    c,
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+
+    let width = tiles.first().ok_or("the contraption is empty")?.len();
+    if tiles.iter().any(|row| row.len() != width) {
+        return Err("the rows of the contraption differ in width".to_string());
+    }
+
+    Ok(tiles)
+}
+
+#[derive(Clone)]
+struct Tile {
+    c: char,
+    visited: HashSet<Direction>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+struct Pos(i32, i32);
+
+impl Pos {
+    fn walk(&self, direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Pos(self.0 - 1, self.1),
+            Direction::Left => Pos(self.0, self.1 - 1),
+            Direction::Right => Pos(self.0, self.1 + 1),
+            Direction::Down => Pos(self.0 + 1, self.1),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// Returns the directions a beam continues in after entering the tile.
+fn deflect(tile: char, direction: Direction) -> Vec<Direction> {
+    use Direction::*;
+
+    match tile {
+        '\\' => match direction {
+            Up => vec![Left],
+            Down => vec![Right],
+            Left => vec![Up],
+            Right => vec![Down],
+        },
+        '/' => match direction {
+            Up => vec![Right],
+            Down => vec![Left],
+            Left => vec![Down],
+            Right => vec![Up],
+        },
+        '|' => match direction {
+            Left | Right => vec![Up, Down],
+            _ => vec![direction],
+        },
+        '-' => match direction {
+            Up | Down => vec![Left, Right],
+            _ => vec![direction],
+        },
+        _ => vec![direction],
+    }
+}
+
+#[derive(Clone)]
+struct Beams {
+    tiles: Vec<Vec<Tile>>,
+    /// The beams about to enter a tile.
+    heads: Vec<(Pos, Direction)>,
+}
+
+impl Beams {
+    fn new(tiles: Vec<Vec<Tile>>, pos: Pos, direction: Direction) -> Beams {
+        Beams {
+            tiles,
+            heads: vec![(pos, direction)],
+        }
+    }
+
+    fn energized(&self) -> usize {
+        self.tiles
+            .iter()
+            .flatten()
+            .filter(|t| !t.visited.is_empty())
+            .count()
+    }
+}
+
+impl Simulation for Beams {
+    fn step(&mut self) -> Result<()> {
+        let (height, width) = (self.tiles.len() as i32, self.tiles[0].len() as i32);
+        let mut heads = Vec::new();
+
+        for (pos, direction) in std::mem::take(&mut self.heads) {
+            let Pos(row, col) = pos;
+            if row < 0 || col < 0 || row >= height || col >= width {
+                continue;
+            }
+
+            // A beam that already passed in the same direction would only
+            // retrace its path.
+            let tile = &mut self.tiles[row as usize][col as usize];
+            if !tile.visited.insert(direction) {
+                continue;
+            }
+
+            for direction in deflect(tile.c, direction) {
+                heads.push((pos.walk(direction), direction));
+            }
+        }
+
+        self.heads = heads;
+
+        Ok(())
+    }
+
+    fn finished(&self) -> bool {
+        self.heads.is_empty()
+    }
+
+    fn state(&self) -> State {
+        let visited = self
+            .tiles
+            .iter()
+            .flatten()
+            .map(|t| t.visited.iter().sorted().collect_vec())
+            .collect_vec();
+
+        State::of(&(visited, &self.heads))
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new(self)
+    }
+
+    fn restore(&mut self, snapshot: &Snapshot) {
+        *self = snapshot.get();
+    }
+
+    /// Energized empty tiles are drawn as #.
+    fn render(&self) -> String {
+        let mut out = self
+            .tiles
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|t| match t.c {
+                        '.' if !t.visited.is_empty() => '#',
+                        c => c,
+                    })
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect::<String>();
+        out.push_str(&format!("energized: {}\n", self.energized()));
+
+        out
+    }
+}
+
+/// Generates a square contraption of mostly empty space, with at least one
+/// mirror.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = 3 + size.min(100);
+
+    let mut rows = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| match rng.gen_range(0..20) {
+                    0 => '|',
+                    1 => '-',
+                    2 => '/',
+                    3 => '\\',
+                    _ => '.',
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+    rows[rng.gen_range(0..side)][rng.gen_range(0..side)] = '/';
+
+    rows.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
@@ -1,18 +1,18 @@
 use std::collections::HashMap;
 
-use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
 
-use crate::util;
+use crate::{
+    answer::{Solution, Unsolved},
+    render::{Color, Image},
+    util,
+};
 
 extern crate itertools;
 extern crate num;
 
-pub fn part1(input: &str) -> Result<String> {
-    let tiles = util::non_empty_lines(input)
-        .map(|l| l.chars().map(|c| c.to_digit(10).unwrap()).collect_vec())
-        .collect_vec();
-
-    let map = Map { tiles };
+pub fn part1(input: &str) -> Solution {
+    let map = parse_map(input).map_err(Unsolved::Parse)?;
 
     let starting_point = Crucible {
         pos: Pos(0, 0),
@@ -24,22 +24,15 @@ pub fn part1(input: &str) -> Result<String> {
 
     let mut cache = HashMap::new();
 
-    let winner = map.move_crucible(starting_point, &mut cache).unwrap();
+    let winner = map
+        .move_crucible(starting_point, &mut cache)
+        .ok_or("the crucible can not reach the factory")?;
 
-    Ok(winner.heat_loss.to_string())
+    Ok(winner.heat_loss.into())
 }
 
-#[test]
-fn test_part1() {
-    assert_eq!("102", part1(TEST_INPUT).unwrap());
-}
-
-pub fn part2(input: &str) -> Result<String> {
-    let tiles = util::non_empty_lines(input)
-        .map(|l| l.chars().map(|c| c.to_digit(10).unwrap()).collect_vec())
-        .collect_vec();
-
-    let map = Map { tiles };
+pub fn part2(input: &str) -> Solution {
+    let map = parse_map(input).map_err(Unsolved::Parse)?;
 
     let starting_point = Crucible {
         pos: Pos(0, 0),
@@ -51,46 +44,62 @@ pub fn part2(input: &str) -> Result<String> {
 
     let mut cache = HashMap::new();
 
-    let winner = map.move_crucible(starting_point, &mut cache).unwrap();
+    let winner = map
+        .move_crucible(starting_point, &mut cache)
+        .ok_or("the crucible can not reach the factory")?;
 
-    Ok(winner.heat_loss.to_string())
+    Ok(winner.heat_loss.into())
 }
 
-#[test]
-fn test_part2() {
-    assert_eq!("94", part2(TEST_INPUT).unwrap());
-}
+/// Draws the heat map, from white for no heat loss to red for the most, with
+/// the path of the crucible over it.
+pub fn render(input: &str, part: u32) -> Result<Image> {
+    let map = parse_map(input)?;
 
-#[test]
-fn test_part2_2() {
-    assert_eq!("71", part2(TEST_INPUT_2).unwrap());
+    let (min_steps, max_steps) = if part == 1 { (1, 3) } else { (4, 10) };
+    let starting_point = Crucible {
+        pos: Pos(0, 0),
+        direction: Direction::None,
+        heat_loss: 0,
+        max_steps,
+        min_steps,
+    };
+
+    let mut cache = HashMap::new();
+
+    let winner = map
+        .move_crucible(starting_point, &mut cache)
+        .ok_or("the crucible can not reach the factory")?;
+
+    let mut image = Image::new(map.tiles[0].len(), map.tiles.len(), Color::WHITE);
+    for (y, row) in map.tiles.iter().enumerate() {
+        for (x, &heat_loss) in row.iter().enumerate() {
+            image.set(x, y, Color::WHITE.mix(Color::RED, heat_loss as f64 / 9.0));
+        }
+    }
+    for Pos(y, x) in map.path(&winner, &cache) {
+        image.set(x, y, Color::BLUE);
+    }
+
+    Ok(image)
 }
 
-#[cfg(test)]
-const TEST_INPUT: &str = r"
-2413432311323
-3215453535623
-3255245654254
-3446585845452
-4546657867536
-1438598798454
-4457876987766
-3637877979653
-4654967986887
-4564679986453
-1224686865563
-2546548887735
-4322674655533
-";
-
-#[cfg(test)]
-const TEST_INPUT_2: &str = r"
-111111111111
-999999991991
-999999991991
-999999991991
-999999999991
-";
+fn parse_map(input: &str) -> Result<Map> {
+    let tiles = util::non_empty_lines(input)
+        .map(|l| {
+            l.chars()
+                .map(|c| c.to_digit(10).ok_or(format!("invalid heat loss {c}")))
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let width = tiles.first().ok_or("the map is empty")?.len();
+    if tiles.iter().any(|row| row.len() != width) {
+        return Err("the rows of the map differ in width".to_string());
+    }
+
+    Ok(Map { tiles })
+}
 
 struct Map {
     tiles: Vec<Vec<u32>>,
@@ -146,11 +155,89 @@ impl Map {
         None
     }
 
+    /// Follows the moves of the crucible back to the start. The cache holds the
+    /// least heat loss of every state the search reached, so the state before
+    /// is one that loses exactly the heat of the tile less.
+    fn path(&self, end: &Crucible, cache: &HashMap<CacheKey, u32>) -> Vec<Pos> {
+        use Direction::*;
+
+        let mut path = vec![end.pos];
+        let (mut pos, mut direction, mut heat_loss) = (end.pos, end.direction, end.heat_loss);
+
+        // Tiles without heat loss could lead the way back in circles.
+        for _ in 0..cache.len() {
+            let (back, previous) = match direction {
+                None => break,
+                Up(steps) => (Down(1), Up(steps - 1)),
+                Down(steps) => (Up(1), Down(steps - 1)),
+                Left(steps) => (Right(1), Left(steps - 1)),
+                Right(steps) => (Left(1), Right(steps - 1)),
+            };
+
+            let Some(before) = pos.walk(back) else {
+                break;
+            };
+            heat_loss -= self.heat_loss(&pos);
+
+            // The crucible went straight on, turned, or just started.
+            let candidates = match previous {
+                Up(0) | Down(0) => (end.min_steps..=end.max_steps)
+                    .flat_map(|steps| [Left(steps), Right(steps)])
+                    .chain([None])
+                    .collect(),
+                Left(0) | Right(0) => (end.min_steps..=end.max_steps)
+                    .flat_map(|steps| [Up(steps), Down(steps)])
+                    .chain([None])
+                    .collect(),
+                _ => vec![previous],
+            };
+
+            let Some(&d) = candidates
+                .iter()
+                .find(|&&d| cache.get(&CacheKey(before, d)) == Some(&heat_loss))
+            else {
+                break;
+            };
+
+            (pos, direction) = (before, d);
+            path.push(pos);
+        }
+
+        path
+    }
+
     fn heat_loss(&self, pos: &Pos) -> u32 {
         self.tiles[pos.0][pos.1]
     }
 }
 
+#[test]
+fn test_path() {
+    let map = parse_map(include_str!("../../examples/2023/day17/sample.txt")).unwrap();
+    let starting_point = Crucible {
+        pos: Pos(0, 0),
+        direction: Direction::None,
+        heat_loss: 0,
+        max_steps: 3,
+        min_steps: 1,
+    };
+
+    let mut cache = HashMap::new();
+    let winner = map.move_crucible(starting_point, &mut cache).unwrap();
+
+    let path = map.path(&winner, &cache);
+    assert!(path.first() == Some(&Pos(12, 12)) && path.last() == Some(&Pos(0, 0)));
+    assert!(path
+        .windows(2)
+        .all(|w| w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1) == 1));
+
+    let heat_loss: u32 = path[..path.len() - 1]
+        .iter()
+        .map(|p| map.heat_loss(p))
+        .sum();
+    assert_eq!(102, heat_loss);
+}
+
 #[derive(Clone, Eq, PartialEq, Hash)]
 struct Crucible {
     pos: Pos,
@@ -267,6 +354,21 @@ impl Pos {
 #[derive(Eq, PartialEq, Hash)]
 struct CacheKey(Pos, Direction);
 
+/// Generates a square map of heat losses. The side is at least five, so the
+/// ultra crucible can reach the end.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = 5 + size.min(140);
+
+    (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| char::from_digit(rng.gen_range(1..=9), 10).unwrap())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
 // -------------------------------------
 
 type Error = String;
@@ -0,0 +1,360 @@
+use std::collections::VecDeque;
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    render::{Color, Image},
+    util,
+};
+
+extern crate itertools;
+extern crate num;
+
+pub fn part1(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input)
+        .map(|s| parse_part1(&s))
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
+
+    let (mut x, mut y, mut l) = (0, 0, 2);
+    let mut vertices = vec![(x, y)];
+
+    for (direction, length) in lines {
+        match direction.as_str() {
+            "U" => y += length,
+            "D" => y -= length,
+            "L" => x -= length,
+            "R" => x += length,
+            _ => {}
+        }
+
+        l += length;
+        vertices.push((x, y))
+    }
+
+    vertices.push(vertices[0]);
+
+    // This is the "shoelace formula" for calculating the area of a polygon
+    //
+    // 2A = (x1*y2 - x2*y1) + (x2*y3 - x3*y2) + ... + (xn*y1 - x1*yn)
+    let area: i64 = vertices
+        .windows(2)
+        .map(|v| v[0].0 * v[1].1 - v[1].0 * v[0].1)
+        .sum();
+
+    Ok(((area.abs() + l) / 2).into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input)
+        .map(|s| parse_part2(&s))
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
+
+    let (mut x, mut y, mut l) = (0, 0, 2);
+    let mut vertices = vec![(x, y)];
+
+    for (direction, length) in lines {
+        match direction.as_str() {
+            "U" => y += length,
+            "D" => y -= length,
+            "L" => x -= length,
+            "R" => x += length,
+            _ => {}
+        }
+
+        l += length;
+        vertices.push((x, y))
+    }
+
+    vertices.push(vertices[0]);
+
+    // This is the "shoelace formula" for calculating the area of a polygon
+    //
+    // 2A = (x1*y2 - x2*y1) + (x2*y3 - x3*y2) + ... + (xn*y1 - x1*yn)
+    let area = vertices
+        .windows(2)
+        .map(|v| v[0].0 * v[1].1 - v[1].0 * v[0].1)
+        .sum::<i64>();
+
+    Ok(((area.abs() + l) / 2).into())
+}
+
+/// Draws the trench of part1, each meter in the color of its dig step. Part2
+/// reads the colors as the plan, so both parts draw the same trench.
+pub fn render(input: &str, _part: u32) -> Result<Image> {
+    let steps = util::non_empty_lines(input)
+        .map(|s| Ok((parse_part1(&s)?, parse_color(&s)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let (mut x, mut y) = (0i64, 0i64);
+    let mut trench = vec![((x, y), Color::DARK)];
+
+    for ((direction, length), color) in steps {
+        let (dx, dy) = match direction.as_str() {
+            "U" => (0, -1),
+            "D" => (0, 1),
+            "L" => (-1, 0),
+            _ => (1, 0),
+        };
+
+        for _ in 0..length {
+            (x, y) = (x + dx, y + dy);
+            trench.push(((x, y), color));
+        }
+    }
+
+    let (min_x, max_x) = trench
+        .iter()
+        .map(|t| t.0 .0)
+        .minmax()
+        .into_option()
+        .unwrap();
+    let (min_y, max_y) = trench
+        .iter()
+        .map(|t| t.0 .1)
+        .minmax()
+        .into_option()
+        .unwrap();
+    let (width, height) = ((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize);
+    if width * height > MAX_TILES {
+        return Err(format!(
+            "the trench is too large to draw: {width} by {height}"
+        ));
+    }
+
+    let mut image = Image::new(width, height, Color::WHITE);
+    for ((x, y), color) in trench {
+        image.set((x - min_x) as usize, (y - min_y) as usize, color);
+    }
+
+    Ok(image)
+}
+
+/// The most tiles an image of a trench can have, which is far more than any
+/// input needs.
+const MAX_TILES: usize = 1 << 24;
+
+/// Digs the trench and counts the cells that a flood fill from the outside does
+/// not reach, instead of using the shoelace formula.
+pub fn part1_flood_fill(input: &str) -> Solution {
+    let moves = util::non_empty_lines(input)
+        .map(|s| parse_part1(&s))
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
+
+    Ok(flood_fill(&moves)?.into())
+}
+
+pub fn part2_flood_fill(input: &str) -> Solution {
+    let moves = util::non_empty_lines(input)
+        .map(|s| parse_part2(&s))
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
+
+    Ok(flood_fill(&moves)?.into())
+}
+
+/// The grid is compressed to the rows and columns where the trench turns, with
+/// the cells in between merged into larger blocks, so the long trenches of
+/// part2 can be filled as well.
+fn flood_fill(moves: &[(String, i64)]) -> Result<i64> {
+    let mut pos = (0, 0);
+    let mut segments = Vec::new();
+
+    for (direction, length) in moves {
+        let (dx, dy) = match direction.as_str() {
+            "U" => (0, 1),
+            "D" => (0, -1),
+            "L" => (-1, 0),
+            "R" => (1, 0),
+            _ => return Err(format!("invalid direction '{direction}'")),
+        };
+
+        let next = (pos.0 + dx * length, pos.1 + dy * length);
+        segments.push((pos, next));
+        pos = next;
+    }
+
+    if segments.is_empty() {
+        return Err("there is no trench".to_string());
+    }
+    if pos != (0, 0) {
+        return Err("the trench does not return to the start".to_string());
+    }
+
+    // Every row and column with a corner is a block of its own, with a block of
+    // outside around the trench.
+    let bounds = |coords: Vec<i64>| {
+        let (min, max) = coords.iter().copied().minmax().into_option().unwrap();
+        coords
+            .iter()
+            .flat_map(|&c| [c, c + 1])
+            .chain([min - 1, max + 2])
+            .sorted()
+            .dedup()
+            .collect_vec()
+    };
+    let xs = bounds(segments.iter().map(|s| s.0 .0).collect());
+    let ys = bounds(segments.iter().map(|s| s.0 .1).collect());
+
+    let mut trench = vec![vec![false; ys.len() - 1]; xs.len() - 1];
+    for (a, b) in segments {
+        let i = |x| xs.binary_search(&x).unwrap();
+        let j = |y| ys.binary_search(&y).unwrap();
+
+        for row in trench[i(a.0.min(b.0))..=i(a.0.max(b.0))].iter_mut() {
+            row[j(a.1.min(b.1))..=j(a.1.max(b.1))].fill(true);
+        }
+    }
+
+    let area = |i: usize, j: usize| (xs[i + 1] - xs[i]) * (ys[j + 1] - ys[j]);
+
+    let mut outside = 0;
+    let mut visited = vec![vec![false; ys.len() - 1]; xs.len() - 1];
+    let mut to_visit = VecDeque::from([(0, 0)]);
+    visited[0][0] = true;
+
+    while let Some((i, j)) = to_visit.pop_front() {
+        outside += area(i, j);
+
+        for (di, dj) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let (Some(i), Some(j)) = (i.checked_add_signed(di), j.checked_add_signed(dj)) else {
+                continue;
+            };
+            if i >= trench.len() || j >= trench[i].len() || trench[i][j] || visited[i][j] {
+                continue;
+            }
+
+            visited[i][j] = true;
+            to_visit.push_back((i, j));
+        }
+    }
+
+    let total = (xs[xs.len() - 1] - xs[0]) * (ys[ys.len() - 1] - ys[0]);
+
+    Ok(total - outside)
+}
+
+/// Generates a trench around a row of overlapping columns of random heights,
+/// which never crosses itself. The colors describe the same trench, scaled up.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let height = 2 + size.min(100) as i64;
+
+    // Each column overlaps the previous one, so the top and the bottom of the
+    // trench never meet.
+    let mut columns = Vec::<(i64, i64, i64)>::new();
+    for _ in 0..2 + size.min(100) / 3 {
+        let width = rng.gen_range(1..=4);
+        let (lo, hi) = loop {
+            let lo = rng.gen_range(-height..height);
+            let hi = rng.gen_range(lo + 1..=height);
+
+            match columns.last() {
+                Some(&(_, prev_lo, prev_hi)) if lo.max(prev_lo) >= hi.min(prev_hi) => continue,
+                _ => break (lo, hi),
+            }
+        };
+        columns.push((width, lo, hi));
+    }
+
+    // Go up the left side, along the top to the right, and back along the
+    // bottom.
+    let mut x = 0;
+    let mut corners = vec![(0, columns[0].1)];
+    for &(width, _, hi) in columns.iter() {
+        corners.push((x, hi));
+        x += width;
+        corners.push((x, hi));
+    }
+    for &(width, lo, _) in columns.iter().rev() {
+        corners.push((x, lo));
+        x -= width;
+        corners.push((x, lo));
+    }
+
+    let scale = rng.gen_range(1..=size.min(1_000) as i64);
+
+    corners
+        .iter()
+        .tuple_windows()
+        .filter_map(|(a, b)| match (b.0 - a.0, b.1 - a.1) {
+            (0, 0) => None,
+            (0, dy) if dy > 0 => Some(("U", 3, dy)),
+            (0, dy) => Some(("D", 1, -dy)),
+            (dx, _) if dx > 0 => Some(("R", 0, dx)),
+            (dx, _) => Some(("L", 2, -dx)),
+        })
+        .coalesce(|a, b| {
+            if a.0 == b.0 {
+                Ok((a.0, a.1, a.2 + b.2))
+            } else {
+                Err((a, b))
+            }
+        })
+        .map(|(direction, code, length)| {
+            format!("{direction} {length} (#{:05x}{code})\n", length * scale)
+        })
+        .collect()
+}
+
+/// Far longer than any step of the inputs, and short enough that the shoelace
+/// formula does not overflow.
+const MAX_LENGTH: i64 = 1 << 20;
+
+fn parse_part1(s: &str) -> Result<(String, i64)> {
+    let invalid = || format!("invalid dig step: {s}");
+    let parts = s.split(" ").collect_vec();
+
+    let direction = parts.first().ok_or_else(invalid)?;
+    if !["U", "D", "L", "R"].contains(direction) {
+        return Err(invalid());
+    }
+    let length = parts
+        .get(1)
+        .and_then(|l| l.parse::<i64>().ok())
+        .filter(|l| (0..=MAX_LENGTH).contains(l))
+        .ok_or_else(invalid)?;
+
+    Ok((direction.to_string(), length))
+}
+
+fn parse_color(s: &str) -> Result<Color> {
+    s.split(" ")
+        .nth(2)
+        .and_then(|c| c.strip_prefix("("))
+        .and_then(|c| c.strip_suffix(")"))
+        .and_then(Color::parse_hex)
+        .ok_or(format!("invalid color: {s}"))
+}
+
+fn parse_part2(s: &str) -> Result<(String, i64)> {
+    let invalid = || format!("invalid color: {s}");
+    let encoded = s
+        .split(" ")
+        .nth(2)
+        .and_then(|c| c.strip_prefix("(#"))
+        .and_then(|c| c.strip_suffix(")"))
+        .filter(|c| c.len() == 6 && c.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(invalid)?;
+
+    Ok((
+        match &encoded[5..] {
+            "0" => "R".to_string(),
+            "1" => "D".to_string(),
+            "2" => "L".to_string(),
+            "3" => "U".to_string(),
+            _ => return Err(invalid()),
+        },
+        i64::from_str_radix(&encoded[..5], 16).map_err(|_| invalid())?,
+    ))
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
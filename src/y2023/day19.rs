@@ -0,0 +1,549 @@
+use core::panic;
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+use std::ops::RangeInclusive;
+
+use crate::{
+    answer::{Solution, Unsolved},
+    util,
+};
+
+extern crate itertools;
+extern crate num;
+
+pub fn part1(input: &str) -> Solution {
+    let (workflows, parts) = parse(input).map_err(Unsolved::Parse)?;
+
+    let start = &workflows["in"];
+
+    let score: u64 = parts
+        .iter()
+        .map(|p| {
+            let mut next = Some(start);
+            while let Some(wf) = next {
+                match wf.evaluate(p).as_str() {
+                    "A" => return Some(p),
+                    "R" => return None,
+                    w => next = workflows.get(&w.to_owned()),
+                }
+            }
+
+            None
+        })
+        .filter_map(|r| r)
+        .map(|p| p.categories.values().sum::<u64>())
+        .sum();
+
+    Ok(score.into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let score: u64 = accepted_ranges(input)
+        .map_err(Unsolved::Parse)?
+        .iter()
+        .map(|ranges| {
+            (ranges.x.end() - ranges.x.start() + 1)
+                * (ranges.m.end() - ranges.m.start() + 1)
+                * (ranges.a.end() - ranges.a.start() + 1)
+                * (ranges.s.end() - ranges.s.start() + 1)
+        })
+        .sum();
+
+    Ok(score.into())
+}
+
+/// Splits each category at the values the rules compare against, and runs one
+/// part from each combination of the pieces through the workflows, instead of
+/// propagating ranges.
+pub fn part2_brute_force(input: &str) -> Solution {
+    let (workflows, _) = input
+        .split_once("\n\n")
+        .ok_or(Unsolved::Parse("expected workflows and parts".to_string()))?;
+    let workflows = parse_workflows(workflows).map_err(Unsolved::Parse)?;
+
+    let mut bounds = HashMap::<&str, Vec<u64>>::new();
+    for category in ["x", "m", "a", "s"] {
+        bounds.insert(category, vec![1, 4001]);
+    }
+    for rule in workflows.values().flat_map(|wf| wf.rules.iter()) {
+        let (category, bound) = match rule {
+            Rule::LessThan(category, value, _) => (category, *value),
+            Rule::GreaterThan(category, value, _) => (category, value + 1),
+            Rule::Default(_) => continue,
+        };
+        bounds
+            .get_mut(category.as_str())
+            .ok_or(format!("unexpected category: {category}"))?
+            .push(bound.clamp(1, 4001));
+    }
+
+    // The pieces of each category as a value in the piece and its length.
+    let pieces = ["x", "m", "a", "s"].map(|category| {
+        bounds[category]
+            .iter()
+            .sorted()
+            .dedup()
+            .tuple_windows()
+            .map(|(&start, &end)| (start, end - start))
+            .collect_vec()
+    });
+
+    let mut score = 0;
+    for combination in pieces.iter().multi_cartesian_product() {
+        let part = Part {
+            categories: ["x", "m", "a", "s"]
+                .iter()
+                .zip(combination.iter())
+                .map(|(category, &&(value, _))| (category.to_string(), value))
+                .collect(),
+        };
+
+        if accepts(&workflows, &part)? {
+            score += combination.iter().map(|&&(_, len)| len).product::<u64>();
+        }
+    }
+
+    Ok(score.into())
+}
+
+/// Runs the part through the workflows, starting at "in".
+fn accepts(workflows: &HashMap<String, Workflow>, part: &Part) -> Result<bool> {
+    let mut name = "in".to_string();
+
+    // A part can visit every workflow at most once, unless they loop.
+    for _ in 0..=workflows.len() {
+        let wf = workflows
+            .get(&name)
+            .ok_or(format!("unknown workflow '{name}'"))?;
+
+        match wf.evaluate(part).as_str() {
+            "A" => return Ok(true),
+            "R" => return Ok(false),
+            next => name = next.to_string(),
+        }
+    }
+
+    Err("the workflows loop".to_string())
+}
+
+/// Generates workflows that only send parts on to later workflows, so they
+/// never loop, followed by some parts.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let count = 1 + size.min(30) / 3;
+
+    let mut names = vec!["in".to_string()];
+    while names.len() < count {
+        let name = (0..rng.gen_range(2..=3))
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect::<String>();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let mut out = String::new();
+
+    for (i, name) in names.iter().enumerate() {
+        let target = |rng: &mut StdRng| match rng.gen_range(0..names.len() - i + 1) {
+            0 => "A".to_string(),
+            1 => "R".to_string(),
+            n => names[i + n - 1].clone(),
+        };
+
+        let rules = (0..rng.gen_range(1..=3))
+            .map(|_| {
+                let target = target(rng);
+                format!(
+                    "{}{}{}:{target}",
+                    ["x", "m", "a", "s"][rng.gen_range(0..4)],
+                    if rng.gen_bool(0.5) { '<' } else { '>' },
+                    rng.gen_range(1..=4000)
+                )
+            })
+            .collect_vec();
+        let default = target(rng);
+
+        out.push_str(&format!("{name}{{{},{default}}}\n", rules.join(",")));
+    }
+
+    out.push('\n');
+
+    for _ in 0..1 + size.min(30) / 2 {
+        let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+        out.push_str(&format!("{{x={x},m={m},a={a},s={s}}}\n"));
+    }
+
+    out
+}
+
+struct Workflow {
+    rules: Vec<Rule>,
+}
+
+impl Workflow {
+    fn evaluate(&self, part: &Part) -> String {
+        for rule in self.rules.iter() {
+            if let Some(result) = rule.match_part(part) {
+                return result;
+            }
+        }
+
+        panic!("no matching rules!")
+    }
+
+    fn acceptable_values(
+        &self,
+        workflows: &HashMap<String, Workflow>,
+        input: &Ranges,
+    ) -> Vec<Ranges> {
+        let mut result = Vec::new();
+
+        let mut ranges = input.clone();
+
+        for rule in self.rules.iter() {
+            match rule {
+                Rule::LessThan(category, value, r) => {
+                    // Create a new restricted range that lets us pass this
+                    // rule.
+                    let restricted = ranges.less_than(&category, value);
+
+                    // Update the input to no longer include the range that made
+                    // us select this path since the different paths are mutually
+                    // exclusive.
+                    ranges = ranges.greater_than_or_equal(&category, value);
+
+                    // The rule may let all or none of the parts through.
+                    if restricted.is_empty() {
+                        continue;
+                    }
+
+                    if r == "A" {
+                        // We have reached a point where the part is accepted.
+                        // Remember the range that led us here.
+                        result.push(restricted);
+                    } else if r != "R" {
+                        // Follow the reference using the restricted range.
+                        let wf = workflows.get(r).unwrap();
+                        result.extend(wf.acceptable_values(workflows, &restricted));
+                    }
+                }
+                Rule::GreaterThan(category, value, r) => {
+                    // Create a new restricted range that lets us pass this
+                    // rule.
+                    let restricted = ranges.greater_than(&category, value);
+
+                    // Update the input to no longer include the range that made
+                    // us select this path since the different paths are mutually
+                    // exclusive.
+                    ranges = ranges.less_than_or_equal(&category, value);
+
+                    // The rule may let all or none of the parts through.
+                    if restricted.is_empty() {
+                        continue;
+                    }
+
+                    if r == "A" {
+                        // We have reached a point where the part is accepted.
+                        // Remember the range that led us here.
+                        result.push(restricted);
+                    } else if r != "R" {
+                        // Follow the reference using the restricted range.
+                        let wf = workflows.get(r).unwrap();
+                        result.extend(wf.acceptable_values(workflows, &restricted));
+                    }
+                }
+                Rule::Default(r) => {
+                    if ranges.is_empty() {
+                        continue;
+                    }
+
+                    if r == "A" {
+                        // All other parts would be accepted, so remember that
+                        // choice as well.
+                        result.push(ranges.clone());
+                    } else if r != "R" {
+                        // Follow the reference using the left over range.
+                        let wf = workflows.get(r).unwrap();
+                        result.extend(wf.acceptable_values(workflows, &ranges));
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Returns the ranges of ratings of the parts that the workflows accept. The
+/// ranges do not overlap.
+pub fn accepted_ranges(input: &str) -> Result<Vec<Ranges>> {
+    let (workflows, _) = input
+        .split_once("\n\n")
+        .ok_or("expected workflows and parts")?;
+
+    Ok(acceptable_values(&parse_workflows(workflows)?))
+}
+
+fn acceptable_values(workflows: &HashMap<String, Workflow>) -> Vec<Ranges> {
+    let start = &workflows["in"];
+    let input = Ranges {
+        x: 1..=4000,
+        m: 1..=4000,
+        a: 1..=4000,
+        s: 1..=4000,
+    };
+
+    start.acceptable_values(workflows, &input)
+}
+
+#[derive(Clone)]
+pub struct Ranges {
+    pub x: RangeInclusive<u64>,
+    pub m: RangeInclusive<u64>,
+    pub a: RangeInclusive<u64>,
+    pub s: RangeInclusive<u64>,
+}
+impl Ranges {
+    fn less_than(&self, category: &String, value: &u64) -> Ranges {
+        let mut r = self.clone();
+
+        match category.as_str() {
+            "x" => r.x = *r.x.start()..=*std::cmp::min(r.x.end(), &(value.saturating_sub(1))),
+            "m" => r.m = *r.m.start()..=*std::cmp::min(r.m.end(), &(value.saturating_sub(1))),
+            "a" => r.a = *r.a.start()..=*std::cmp::min(r.a.end(), &(value.saturating_sub(1))),
+            "s" => r.s = *r.s.start()..=*std::cmp::min(r.s.end(), &(value.saturating_sub(1))),
+            _ => panic!("unexpected category: {category}"),
+        };
+
+        r
+    }
+
+    fn less_than_or_equal(&self, category: &String, value: &u64) -> Ranges {
+        let mut r = self.clone();
+
+        match category.as_str() {
+            "x" => r.x = *r.x.start()..=*std::cmp::min(r.x.end(), &(value)),
+            "m" => r.m = *r.m.start()..=*std::cmp::min(r.m.end(), &(value)),
+            "a" => r.a = *r.a.start()..=*std::cmp::min(r.a.end(), &(value)),
+            "s" => r.s = *r.s.start()..=*std::cmp::min(r.s.end(), &(value)),
+            _ => panic!("unexpected category: {category}"),
+        };
+
+        r
+    }
+
+    fn greater_than(&self, category: &String, value: &u64) -> Ranges {
+        let mut r = self.clone();
+
+        match category.as_str() {
+            "x" => r.x = *std::cmp::max(r.x.start(), &(value + 1))..=*r.x.end(),
+            "m" => r.m = *std::cmp::max(r.m.start(), &(value + 1))..=*r.m.end(),
+            "a" => r.a = *std::cmp::max(r.a.start(), &(value + 1))..=*r.a.end(),
+            "s" => r.s = *std::cmp::max(r.s.start(), &(value + 1))..=*r.s.end(),
+            _ => panic!("unexpected category: {category}"),
+        };
+
+        r
+    }
+
+    fn greater_than_or_equal(&self, category: &String, value: &u64) -> Ranges {
+        let mut r = self.clone();
+
+        match category.as_str() {
+            "x" => r.x = *std::cmp::max(r.x.start(), &value)..=*r.x.end(),
+            "m" => r.m = *std::cmp::max(r.m.start(), &value)..=*r.m.end(),
+            "a" => r.a = *std::cmp::max(r.a.start(), &value)..=*r.a.end(),
+            "s" => r.s = *std::cmp::max(r.s.start(), &value)..=*r.s.end(),
+            _ => panic!("unexpected category: {category}"),
+        };
+
+        r
+    }
+
+    fn is_empty(&self) -> bool {
+        self.x.is_empty() || self.m.is_empty() || self.a.is_empty() || self.s.is_empty()
+    }
+}
+
+fn parse(input: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>)> {
+    let (workflows, parts) = input
+        .split_once("\n\n")
+        .ok_or("expected workflows and parts")?;
+
+    Ok((parse_workflows(workflows)?, parse_parts(parts)?))
+}
+
+fn parse_workflows(input: &str) -> Result<HashMap<String, Workflow>> {
+    let workflows = util::non_empty_lines(input)
+        .map(|s| {
+            let (name, rules) = s
+                .strip_suffix("}")
+                .and_then(|s| s.split_once("{"))
+                .ok_or(format!("invalid workflow: {s}"))?;
+            let rules = rules
+                .split(",")
+                .map(|s| parse_rule(s))
+                .collect::<Result<Vec<_>>>()?;
+
+            // Every part has to leave the workflow through one of the rules.
+            if !matches!(rules.last(), Some(Rule::Default(_))) {
+                return Err(format!("the workflow {name} has no default rule"));
+            }
+
+            Ok((name.to_owned(), Workflow { rules }))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+
+    check_workflows(&workflows)?;
+
+    Ok(workflows)
+}
+
+/// Checks that the rules only send parts to workflows that exist, and that the
+/// workflows never loop, so every part ends up accepted or rejected.
+fn check_workflows(workflows: &HashMap<String, Workflow>) -> Result<()> {
+    if !workflows.contains_key("in") {
+        return Err("there is no workflow named in".to_string());
+    }
+
+    for rule in workflows.values().flat_map(|wf| wf.rules.iter()) {
+        let target = rule.target();
+        if target != "A" && target != "R" && !workflows.contains_key(target) {
+            return Err(format!("unknown workflow '{target}'"));
+        }
+    }
+
+    // A workflow that is reached again while its own rules are being followed
+    // is part of a loop.
+    fn visit<'a>(
+        name: &'a str,
+        workflows: &'a HashMap<String, Workflow>,
+        path: &mut HashSet<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Result<()> {
+        if done.contains(name) {
+            return Ok(());
+        }
+        if !path.insert(name) {
+            return Err(format!("the workflows loop through {name}"));
+        }
+
+        for rule in workflows[name].rules.iter() {
+            if workflows.contains_key(rule.target()) {
+                visit(rule.target(), workflows, path, done)?;
+            }
+        }
+
+        path.remove(name);
+        done.insert(name);
+
+        Ok(())
+    }
+
+    let mut done = HashSet::new();
+    for name in workflows.keys() {
+        visit(name, workflows, &mut HashSet::new(), &mut done)?;
+    }
+
+    Ok(())
+}
+
+struct Part {
+    categories: HashMap<String, u64>,
+}
+
+fn parse_parts(input: &str) -> Result<Vec<Part>> {
+    util::non_empty_lines(input)
+        .map(|s| {
+            let invalid = || format!("invalid part: {s}");
+
+            let categories = s
+                .strip_prefix("{")
+                .and_then(|s| s.strip_suffix("}"))
+                .ok_or_else(invalid)?
+                .split(",")
+                .map(|s| {
+                    let (category, value) = s.split_once("=").ok_or_else(invalid)?;
+                    let value = value.parse::<u32>().map_err(|_| invalid())?;
+
+                    Ok((category.to_owned(), value as u64))
+                })
+                .collect::<Result<HashMap<_, _>>>()?;
+
+            // The rules look up the categories by name.
+            if CATEGORIES.iter().any(|c| !categories.contains_key(*c)) {
+                return Err(invalid());
+            }
+
+            Ok(Part { categories })
+        })
+        .collect()
+}
+
+const CATEGORIES: [&str; 4] = ["x", "m", "a", "s"];
+
+enum Rule {
+    LessThan(String, u64, String),
+    GreaterThan(String, u64, String),
+    Default(String),
+}
+
+impl Rule {
+    /// The workflow, or A or R, that the rule sends parts to.
+    fn target(&self) -> &str {
+        match self {
+            Rule::LessThan(_, _, result)
+            | Rule::GreaterThan(_, _, result)
+            | Rule::Default(result) => result,
+        }
+    }
+
+    fn match_part(&self, p: &Part) -> Option<String> {
+        match self {
+            Rule::LessThan(category, value, result) if p.categories[category] < *value => {
+                Some(result.clone())
+            }
+            Rule::GreaterThan(category, value, result) if p.categories[category] > *value => {
+                Some(result.clone())
+            }
+            Rule::Default(result) => Some(result.clone()),
+            _ => None,
+        }
+    }
+}
+
+fn parse_rule(input: &str) -> Result<Rule> {
+    let Some((condition, result)) = input.split_once(":") else {
+        return Ok(Rule::Default(input.to_owned()));
+    };
+
+    let invalid = || format!("invalid rule: {input}");
+    let (category, value, less) = if let Some((category, value)) = condition.split_once(">") {
+        (category, value, false)
+    } else if let Some((category, value)) = condition.split_once("<") {
+        (category, value, true)
+    } else {
+        return Err(invalid());
+    };
+
+    if !CATEGORIES.contains(&category) {
+        return Err(invalid());
+    }
+    let value = value.parse::<u32>().map_err(|_| invalid())? as u64;
+    let (category, result) = (category.to_owned(), result.to_owned());
+
+    if less {
+        Ok(Rule::LessThan(category, value, result))
+    } else {
+        Ok(Rule::GreaterThan(category, value, result))
+    }
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
@@ -0,0 +1,141 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    trace, util,
+};
+
+pub fn part1(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input).collect::<Vec<_>>();
+
+    let games = lines
+        .into_iter()
+        .map(parse_game)
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
+
+    let score = games.into_iter().map(get_valid_game_id).sum::<u64>();
+
+    Ok(score.into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input).collect::<Vec<_>>();
+
+    let games = lines
+        .into_iter()
+        .map(parse_game)
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
+
+    let score = games.into_iter().map(get_game_power).sum::<Result<u64>>()?;
+
+    Ok(score.into())
+}
+
+fn parse_game(s: String) -> Result<Game> {
+    let invalid = || format!("invalid game: {s}");
+
+    let (name, sets) = s.split_once(":").ok_or_else(invalid)?;
+    let id = name
+        .strip_prefix("Game ")
+        .and_then(|id| id.parse::<u32>().ok())
+        .ok_or_else(invalid)?;
+    let cube_sets = sets.split(";").collect::<Vec<&str>>();
+
+    let mut game = Game{id, red: 0, green: 0, blue: 0};
+
+    for set in cube_sets {
+        let cubes = set.split(",");
+        for cube in cubes {
+            let (count, color) = cube.trim().split_once(" ").ok_or_else(invalid)?;
+            let count = count.parse::<u32>().map_err(|_| invalid())?;
+
+            match color {
+                "red" => if count > game.red { game.red = count },
+                "green" => if count > game.green { game.green = count },
+                "blue" => if count > game.blue { game.blue = count },
+                _ => return Err(invalid()),
+            }
+        }
+    }
+
+    Ok(game)
+}
+
+fn get_valid_game_id(g: Game) -> u64 {
+    let valid = g.red <= 12 && g.green <= 13 && g.blue <= 14;
+
+    trace::emit!(
+        "game",
+        id = g.id,
+        red = g.red,
+        green = g.green,
+        blue = g.blue,
+        valid = valid,
+    );
+
+    if valid {
+        g.id as u64
+    } else {
+        0
+    }
+}
+
+fn get_game_power(g: Game) -> Result<u64> {
+    let power = (g.red as u64 * g.green as u64)
+        .checked_mul(g.blue as u64)
+        .ok_or_else(|| format!("the power of game {} is too large", g.id))?;
+
+    trace::emit!(
+        "game",
+        id = g.id,
+        red = g.red,
+        green = g.green,
+        blue = g.blue,
+        power = power,
+    );
+
+    Ok(power)
+}
+
+struct Game {
+    id: u32,
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+/// Generates games of a few draws each, with counts around the limits of
+/// part 1.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+    (1..=1 + size.min(1_000))
+        .map(|id| {
+            let draws = (0..rng.gen_range(1..=5))
+                .map(|_| {
+                    let mut colors = COLORS.to_vec();
+                    colors.shuffle(rng);
+                    colors.truncate(rng.gen_range(1..=3));
+
+                    colors
+                        .iter()
+                        .map(|c| format!("{} {c}", rng.gen_range(1..=16)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+
+            format!("Game {id}: {draws}\n")
+        })
+        .collect()
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
@@ -0,0 +1,618 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    params, progress,
+    repl::Model,
+    simulation::{self, Simulation, Snapshot, State},
+    util,
+};
+
+extern crate itertools;
+extern crate num;
+
+pub fn part1(input: &str) -> Solution {
+    pulse_score(&Network::parse(input).map_err(Unsolved::Parse)?)
+}
+
+pub fn part2(input: &str) -> Solution {
+    presses_until_rx(&Network::parse(input).map_err(Unsolved::Parse)?)
+}
+
+fn pulse_score(network: &Network) -> Solution {
+    let mut network = network.clone();
+
+    let presses = params::get("presses", 1000)?;
+    progress::set_total(presses);
+    simulation::run(&mut network, presses)?;
+
+    let score = network
+        .high
+        .checked_mul(network.low)
+        .ok_or("there are too many pulses to count")?;
+
+    Ok(score.into())
+}
+
+fn presses_until_rx(network: &Network) -> Solution {
+    let mut network = network.clone();
+
+    // The rx has a single feeder module. We are interested in finding out when
+    // all of those inputs are high at the same time.
+    //
+    // Each input will require a certain number of button presses
+    // before giving a high output, and the total number of button presses
+    // can be calculated by finding the LCM of those cycles.
+    let rx_feeder = network
+        .modules
+        .iter()
+        .find(|m| m.destinations().iter().any(|d| d == "rx"))
+        .ok_or("no module sends pulses to rx")?;
+
+    // Find the inputs of the rx feeder module and initialize the cycle
+    // counters for them to zero.
+    let rx_feeder_id = rx_feeder.id();
+    let mut rx_feeder_inputs: HashMap<String, usize> =
+        rx_feeder.inputs().into_iter().map(|i| (i, 0)).collect();
+
+    let found = network.run_until(MAX_PRESSES, |network| {
+        // If an input sent a high pulse we should store the number of presses
+        // (but only the first time).
+        for (input, cycle) in rx_feeder_inputs.iter_mut() {
+            if *cycle == 0 && network.sent_high.contains(input) {
+                *cycle = network.presses;
+            }
+        }
+
+        // Once we have found the cycle values for all inputs we can stop
+        // pressing the button.
+        rx_feeder_inputs.values().all(|&v| v > 0)
+    })?;
+
+    if found.is_none() {
+        return Err(format!(
+            "the inputs of {rx_feeder_id} do not all send a high pulse within {MAX_PRESSES} presses"
+        )
+        .into());
+    }
+
+    // Calculate the LCM of all the feeder input cycles.
+    let count = rx_feeder_inputs
+        .values()
+        .try_fold(1usize, |acc, &v| {
+            (acc / num::integer::gcd(acc, v)).checked_mul(v)
+        })
+        .ok_or("the number of presses is too large")?;
+
+    Ok(count.into())
+}
+
+/// The counters of the inputs send a pulse to rx within a few thousand presses,
+/// and each press sends a few hundred pulses.
+const MAX_PRESSES: usize = 1 << 16;
+const MAX_PULSES: usize = 100_000;
+
+fn never_stops() -> Error {
+    format!("a button press sends more than {MAX_PULSES} pulses")
+}
+
+pub fn model(input: &str) -> Result<Box<dyn Model>> {
+    Ok(Box::new(Network::parse(input)?))
+}
+
+/// Each step is a press of the button, and runs until all its pulses have been
+/// handled.
+pub fn simulation(input: &str) -> Result<Box<dyn Simulation>> {
+    Ok(Box::new(Network::parse(input)?))
+}
+
+struct Network {
+    /// The modules in the order they are listed in the input.
+    modules: Vec<Box<dyn Module>>,
+    index: HashMap<String, usize>,
+    presses: usize,
+    low: u64,
+    high: u64,
+    /// The modules that sent a high pulse during the last press.
+    sent_high: HashSet<String>,
+}
+
+impl Network {
+    fn parse(input: &str) -> Result<Network> {
+        // Create a lookup table for all the modules in the network. A module
+        // that is listed twice replaces the first one.
+        let mut modules: Vec<Box<dyn Module>> = Vec::new();
+        let mut index = HashMap::new();
+
+        for line in util::non_empty_lines(input) {
+            let module = parse_module(&line)?;
+            match index.get(&module.id()) {
+                Some(&i) => modules[i] = module,
+                None => {
+                    index.insert(module.id(), modules.len());
+                    modules.push(module);
+                }
+            }
+        }
+
+        // Make sure all modules know about their inputs. Pulses to a module
+        // that is not listed are dropped.
+        let destinations = modules
+            .iter()
+            .flat_map(|m| m.destinations().into_iter().map(|d| (m.id(), d)))
+            .collect_vec();
+
+        for (id, d) in destinations {
+            if let Some(&i) = index.get(&d) {
+                modules[i].set_input(&id);
+            }
+        }
+
+        Ok(Network {
+            modules,
+            index,
+            presses: 0,
+            low: 0,
+            high: 0,
+            sent_high: HashSet::new(),
+        })
+    }
+}
+
+impl Clone for Network {
+    fn clone(&self) -> Network {
+        Network {
+            modules: self.modules.iter().map(|m| m.clone_box()).collect(),
+            index: self.index.clone(),
+            presses: self.presses,
+            low: self.low,
+            high: self.high,
+            sent_high: self.sent_high.clone(),
+        }
+    }
+}
+
+impl Simulation for Network {
+    fn step(&mut self) -> Result<()> {
+        self.presses += 1;
+        self.sent_high.clear();
+        progress::inc();
+
+        // Pulses are always processed in the order they are sent. So, if a
+        // pulse is sent to modules a, b, and c, and then module a processes
+        // its pulse and sends more pulses, the pulses sent to modules b and c
+        // would have to be handled first.
+        //
+        // This is a breadth first traversal of the module tree, so let's use a
+        // fifo queue,
+
+        let mut fifo = VecDeque::new();
+
+        // When you push the button, a single low pulse is sent directly to the
+        // broadcaster module.
+        fifo.push_back(vec![PulseRequest {
+            to: "broadcaster".to_string(),
+            from: "button".to_string(),
+            pulse: Pulse::Low,
+        }]);
+
+        // After pushing the button, you must wait until all pulses have been
+        // delivered and fully handled before pushing it again.
+        let mut pulses = 0;
+        while let Some(reqs) = fifo.pop_front() {
+            for req in reqs {
+                pulses += 1;
+                if pulses > MAX_PULSES {
+                    return Err(never_stops());
+                }
+
+                match req.pulse {
+                    Pulse::High => {
+                        self.high += 1;
+                        self.sent_high.insert(req.from.clone());
+                    }
+                    Pulse::Low => self.low += 1,
+                }
+
+                // Find the destination module and send the pulse to it.
+                if let Some(&i) = self.index.get(&req.to) {
+                    let out = self.modules[i].send_pulse(&req.from, &req.pulse);
+
+                    // This will likely cause the module to send out new pulses
+                    // so add them to the processing queue.
+                    if !out.is_empty() {
+                        fifo.push_back(out);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Only the modules are part of the state, the counters of presses and
+    /// pulses would keep every state apart.
+    fn state(&self) -> State {
+        State::of(&self.modules.iter().map(|m| m.state()).collect_vec())
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new(self)
+    }
+
+    fn restore(&mut self, snapshot: &Snapshot) {
+        *self = snapshot.get();
+    }
+
+    /// Draws the flip-flops as 1 when they are on, and the conjunctions as the
+    /// pulses they remember from their inputs in alphabetical order, 1 for
+    /// high.
+    fn render(&self) -> String {
+        let mut out = format!(
+            "presses: {}, low pulses: {}, high pulses: {}\n",
+            self.presses, self.low, self.high
+        );
+
+        for m in self.modules.iter() {
+            let state: String = m
+                .state()
+                .iter()
+                .map(|&on| if on { '1' } else { '0' })
+                .collect();
+            out.push_str(format!("{} {state}", m.id()).trim_end());
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+impl Model for Network {
+    fn part1(&self) -> Solution {
+        pulse_score(self)
+    }
+
+    fn part2(&self) -> Solution {
+        presses_until_rx(self)
+    }
+
+    fn views(&self) -> Vec<&'static str> {
+        vec!["modules"]
+    }
+
+    fn show(&self, view: &str) -> Option<String> {
+        if view != "modules" {
+            return None;
+        }
+
+        Some(
+            self.modules
+                .iter()
+                .map(|m| {
+                    format!(
+                        "{} <- [{}] -> [{}]\n",
+                        m.id(),
+                        m.inputs().iter().sorted().join(", "),
+                        m.destinations().join(", ")
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Pulse {
+    High,
+    Low,
+}
+
+struct PulseRequest {
+    to: String,
+    from: String,
+    pulse: Pulse,
+}
+
+trait Module {
+    fn id(&self) -> String;
+    fn destinations(&self) -> Vec<String>;
+    fn set_input(&mut self, id: &str);
+    fn inputs(&self) -> Vec<String>;
+    fn send_pulse(&mut self, from: &str, pulse: &Pulse) -> Vec<PulseRequest>;
+
+    /// What the module remembers between pulses.
+    fn state(&self) -> Vec<bool>;
+
+    fn clone_box(&self) -> Box<dyn Module>;
+}
+
+fn parse_module(input: &str) -> Result<Box<dyn Module>> {
+    let (name, destinations) = input
+        .split_once(" -> ")
+        .ok_or(format!("invalid module: {input}"))?;
+
+    if name == "broadcaster" {
+        Ok(Box::new(Broadcast {
+            id: name.to_string(),
+            inputs: Vec::new(),
+            destinations: destinations
+                .split(",")
+                .map(|s| s.trim().to_string())
+                .collect_vec(),
+        }))
+    } else if name.starts_with("%") {
+        Ok(Box::new(FlipFlop {
+            on: false,
+            id: name[1..].to_string(),
+            inputs: Vec::new(),
+            destinations: destinations
+                .split(",")
+                .map(|s| s.trim().to_string())
+                .collect_vec(),
+        }))
+    } else if name.starts_with("&") {
+        Ok(Box::new(Conjunction {
+            memory: HashMap::new(),
+            id: name[1..].to_string(),
+            destinations: destinations
+                .split(",")
+                .map(|s| s.trim().to_string())
+                .collect_vec(),
+        }))
+    } else {
+        Err(format!("invalid name: {name}"))
+    }
+}
+
+#[derive(Clone)]
+struct FlipFlop {
+    id: String,
+    on: bool,
+    inputs: Vec<String>,
+    destinations: Vec<String>,
+}
+
+impl Module for FlipFlop {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn destinations(&self) -> Vec<String> {
+        self.destinations.clone()
+    }
+
+    fn set_input(&mut self, id: &str) {
+        self.inputs.push(id.to_string());
+    }
+
+    fn inputs(&self) -> Vec<String> {
+        self.inputs.clone()
+    }
+
+    fn state(&self) -> Vec<bool> {
+        vec![self.on]
+    }
+
+    fn clone_box(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
+    }
+
+    fn send_pulse(&mut self, _from: &str, pulse: &Pulse) -> Vec<PulseRequest> {
+        let mut requests = Vec::new();
+        match pulse {
+            Pulse::High => {
+                // If a flip-flop module receives a high pulse, it is
+                // ignored and nothing happens.
+            }
+            Pulse::Low => {
+                // However, if a flip-flop module receives a low pulse, it
+                // flips between on and off.
+                if self.on {
+                    // If it was on, it turns off and sends a low pulse.
+                    self.on = false;
+                    for d in self.destinations.iter_mut() {
+                        requests.push(PulseRequest {
+                            to: d.clone(),
+                            from: self.id.clone(),
+                            pulse: Pulse::Low,
+                        });
+                    }
+                } else {
+                    // If it was off, it turns on and sends a high pulse.
+                    self.on = true;
+                    for d in self.destinations.iter_mut() {
+                        requests.push(PulseRequest {
+                            to: d.clone(),
+                            from: self.id.clone(),
+                            pulse: Pulse::High,
+                        });
+                    }
+                }
+            }
+        }
+
+        requests
+    }
+}
+
+#[derive(Clone)]
+struct Conjunction {
+    id: String,
+    memory: HashMap<String, Pulse>,
+    destinations: Vec<String>,
+}
+
+impl Module for Conjunction {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn destinations(&self) -> Vec<String> {
+        self.destinations.clone()
+    }
+
+    fn set_input(&mut self, id: &str) {
+        self.memory.insert(id.to_string(), Pulse::Low);
+    }
+
+    fn inputs(&self) -> Vec<String> {
+        self.memory.keys().map(|s| s.to_string()).collect_vec()
+    }
+
+    /// The pulses remembered from the inputs, in alphabetical order.
+    fn state(&self) -> Vec<bool> {
+        self.memory
+            .iter()
+            .sorted_by(|a, b| a.0.cmp(b.0))
+            .map(|(_, &p)| p == Pulse::High)
+            .collect()
+    }
+
+    fn clone_box(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
+    }
+
+    fn send_pulse(&mut self, from: &str, pulse: &Pulse) -> Vec<PulseRequest> {
+        let mut requests = Vec::new();
+
+        // When a pulse is received, the conjunction module first updates its
+        // memory for that input.
+        self.memory.insert(from.to_owned(), *pulse);
+
+        if self.memory.values().all(|&p| p == Pulse::High) {
+            // Then, if it remembers high pulses for all inputs, it sends a
+            // low pulse.
+            for d in self.destinations.iter() {
+                requests.push(PulseRequest {
+                    to: d.clone(),
+                    from: self.id.clone(),
+                    pulse: Pulse::Low,
+                });
+            }
+        } else {
+            // Otherwise, it sends a high pulse
+            for d in self.destinations.iter_mut() {
+                requests.push(PulseRequest {
+                    to: d.clone(),
+                    from: self.id.clone(),
+                    pulse: Pulse::High,
+                });
+            }
+        }
+
+        requests
+    }
+}
+
+#[derive(Clone)]
+struct Broadcast {
+    id: String,
+    inputs: Vec<String>,
+    destinations: Vec<String>,
+}
+
+impl Module for Broadcast {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn destinations(&self) -> Vec<String> {
+        self.destinations.clone()
+    }
+
+    fn set_input(&mut self, id: &str) {
+        self.inputs.push(id.to_string());
+    }
+
+    fn inputs(&self) -> Vec<String> {
+        self.inputs.clone()
+    }
+
+    fn state(&self) -> Vec<bool> {
+        Vec::new()
+    }
+
+    fn clone_box(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
+    }
+
+    fn send_pulse(&mut self, _from: &str, pulse: &Pulse) -> Vec<PulseRequest> {
+        let mut requests = Vec::new();
+
+        // There is a single broadcast module (named broadcaster). When
+        // it receives a pulse, it sends the same pulse to all of its
+        // destination modules.
+        for d in self.destinations.iter() {
+            requests.push(PulseRequest {
+                to: d.clone(),
+                from: self.id.clone(),
+                pulse: *pulse,
+            });
+        }
+
+        requests
+    }
+}
+
+/// Generates a network with the shape of the inputs: the broadcaster starts a
+/// few binary counters of flip-flops, each with a conjunction that resets its
+/// counter after a different number of presses. The conjunctions feed rx
+/// through an inverter each and a final conjunction.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let bits = 4 + size.min(40) / 5;
+    let counters = rng.gen_range(2..=4);
+
+    let mut names = HashSet::from(["rx".to_string()]);
+    let mut name = |rng: &mut StdRng| loop {
+        let name = (0..2)
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect::<String>();
+        if names.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    let output = name(rng);
+    let mut lines = vec![format!("&{output} -> rx")];
+    let mut starts = Vec::new();
+
+    for _ in 0..counters {
+        // The counter resets after an odd number of presses with the top bit
+        // set.
+        let presses = rng.gen_range(1 << (bits - 1)..1 << bits) | 1;
+
+        let flip_flops = (0..bits).map(|_| name(rng)).collect_vec();
+        let (conjunction, inverter) = (name(rng), name(rng));
+
+        let mut resets = vec![flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut destinations = flip_flops.get(bit + 1).into_iter().cloned().collect_vec();
+            if presses & (1 << bit) != 0 {
+                destinations.push(conjunction.clone());
+            } else {
+                resets.push(flip_flop.clone());
+            }
+            lines.push(format!("%{flip_flop} -> {}", destinations.join(", ")));
+        }
+        resets.push(inverter.clone());
+
+        lines.push(format!("&{conjunction} -> {}", resets.join(", ")));
+        lines.push(format!("&{inverter} -> {output}"));
+        starts.push(flip_flops[0].clone());
+    }
+
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    lines.shuffle(rng);
+
+    lines.iter().map(|l| format!("{l}\n")).collect()
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
@@ -0,0 +1,475 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    params,
+    render::{Color, Image},
+    simulation::{Simulation, Snapshot, State},
+    util,
+};
+
+extern crate itertools;
+extern crate num;
+
+pub fn part1(input: &str) -> Solution {
+    let map: Map = input.parse().map_err(Unsolved::Parse)?;
+    let result = map.walk(params::get("steps", 64)?);
+
+    Ok(result.into())
+}
+
+#[test]
+fn test_part1() {
+    let map: Map = TEST_INPUT.parse().unwrap();
+    let result = map.walk(6);
+    assert_eq!(16, result);
+}
+
+pub fn part2(input: &str) -> Solution {
+    let map: Map = input.parse().map_err(Unsolved::Parse)?;
+    let steps = params::get("steps", 26501365)?;
+    let size = map.tiles.len();
+
+    // The shortcut below only works for maps and steps like the puzzle's,
+    // others are walked step by step.
+    if size < 3
+        || !has_input_shape(&map)
+        || steps < size
+        || steps % size != size / 2
+        || !(steps / size).is_multiple_of(2)
+    {
+        return Ok(walk_repeated(&map, steps).into());
+    }
+
+    // This is the maximum number of maps we can traverse in a straight line
+    // going up, down, left or right.
+    //
+    // This works because the input has an empty row and col in the exact middle
+    // of the map, which allows straight traversal through each map.
+    //
+    // Since there is also an empty diagonal in the input between the
+    // "entry points" on each side, this is also the exact number of grids we
+    // can visit by going diagonally.
+    let grid_width = steps / size - 1;
+
+    // We can now calculate the number of "odd" grids we can fully visit. This
+    // is easiest to see by drawing the grids and color the "odd" ones. It will
+    // form a kind of diagonal square of colored grids.
+
+    let odd_count = (grid_width / 2 * 2 + 1).pow(2);
+    let even_count = ((grid_width + 1) / 2 * 2).pow(2);
+
+    // So let's count the number of points we can visit in the odd and even
+    // grids. The odd grids are the ones with the same parity as the number of
+    // steps, which is odd in the puzzle.
+    let odd_points = map.walk_from(&map.start, size * 2 + steps % 2);
+    let even_points = map.walk_from(&map.start, size * 2 + 1 - steps % 2);
+
+    // Now we need to handle the "corner cases" at the outer points of the the
+    // huge grid of grids. This is done by starting at the entry point of each
+    // side of the grid and checking how many points we can reach from there.
+    let steps = size - 1;
+    let corners = [
+        map.walk_from(&Pos(size as i32 - 1, map.start.1), steps), // Top
+        map.walk_from(&Pos(map.start.0, 0), steps),               // Right
+        map.walk_from(&Pos(0, map.start.1), steps),               // Bottom
+        map.walk_from(&Pos(map.start.0, size as i32 - 1), steps), // Left
+    ];
+
+    // The last parts to cover are the "edges" of the reachable grids. They will
+    // be partially covered in two different ways: one where we come from a
+    // partially filled grid, giving a smaller amount of steps covering the edge
+    // grid, and one where we come from a fully filled grid, giving us more
+    // steps left to cover the edge.
+    let steps = size / 2 - 1;
+    let small_edges = [
+        map.walk_from(&Pos(size as i32 - 1, 0), steps), // Top right
+        map.walk_from(&Pos(size as i32 - 1, size as i32 - 1), steps), // Top left
+        map.walk_from(&Pos(0, 0), steps),               // Bottom right
+        map.walk_from(&Pos(0, size as i32 - 1), steps), // Bottom left
+    ];
+
+    let steps = (size * 3) / 2 - 1;
+    let large_edges = [
+        map.walk_from(&Pos(size as i32 - 1, 0), steps), // Top right
+        map.walk_from(&Pos(size as i32 - 1, size as i32 - 1), steps), // Top left
+        map.walk_from(&Pos(0, 0), steps),               // Bottom right
+        map.walk_from(&Pos(0, size as i32 - 1), steps), // Bottom left
+    ];
+
+    let result = odd_count * odd_points
+        + even_count * even_points
+        + corners.iter().sum::<usize>()
+        + (grid_width + 1) * small_edges.iter().sum::<usize>()
+        + grid_width * large_edges.iter().sum::<usize>();
+
+    Ok(result.into())
+}
+
+/// Walks the repeated map step by step. Only feasible for a small number of
+/// steps, which can be set with the "steps" param.
+pub fn part2_brute_force(input: &str) -> Solution {
+    let map: Map = input.parse().map_err(Unsolved::Parse)?;
+    let steps = params::get("steps", 26501365)?;
+
+    let reached = repeated_walk(&map).nth(steps).unwrap();
+
+    Ok(reached.into())
+}
+
+/// Whether the map has the shape part2 relies on: an odd size with the start
+/// in the middle, and an empty middle row and column and border.
+fn has_input_shape(map: &Map) -> bool {
+    let size = map.tiles.len();
+    let middle = size / 2;
+    let open = |i: usize, j: usize| map.tiles[i][j] != '#';
+
+    size % 2 == 1
+        && map.start == Pos(middle as i32, middle as i32)
+        && (0..size).all(|k| open(k, middle) && open(middle, k) && open(0, k) && open(k, 0))
+}
+
+/// The number of plots reachable on the repeated map after 0, 1, 2, ... steps.
+fn repeated_walk(map: &Map) -> impl Iterator<Item = usize> + '_ {
+    let size = map.tiles.len() as i32;
+    let open = move |&Pos(i, j): &Pos| {
+        map.tiles[i.rem_euclid(size) as usize][j.rem_euclid(size) as usize] != '#'
+    };
+
+    let mut visited = HashSet::from([map.start]);
+    let mut frontier = vec![map.start];
+    let mut reached = [1, 0];
+
+    (0..).map(move |step| {
+        if step > 0 {
+            frontier = frontier
+                .iter()
+                .flat_map(|&Pos(i, j)| [Pos(i - 1, j), Pos(i + 1, j), Pos(i, j - 1), Pos(i, j + 1)])
+                .filter(open)
+                .filter(|pos| visited.insert(*pos))
+                .collect();
+
+            reached[step % 2] += frontier.len();
+        }
+
+        reached[step % 2]
+    })
+}
+
+/// How many times in a row the second differences must agree before the walk
+/// is extrapolated.
+const STABLE_DIFFERENCES: usize = 4;
+
+/// Walks the repeated map of any shape. Every two maps' worth of steps the
+/// walk reaches another ring of maps, so once it has spread past the rocks
+/// around the start, the plots reached at those steps grow quadratically. The
+/// walk goes on until the second differences settle, and the rest follows from
+/// them.
+fn walk_repeated(map: &Map, steps: usize) -> usize {
+    let period = 2 * map.tiles.len();
+    let mut samples: Vec<i64> = Vec::new();
+
+    for (step, reached) in repeated_walk(map).enumerate() {
+        if step == steps {
+            return reached;
+        }
+        if step % period != steps % period {
+            continue;
+        }
+
+        samples.push(reached as i64);
+        let second = samples
+            .windows(3)
+            .map(|w| w[2] - 2 * w[1] + w[0])
+            .collect_vec();
+
+        if second.len() >= STABLE_DIFFERENCES
+            && second[second.len() - STABLE_DIFFERENCES..]
+                .iter()
+                .all_equal()
+        {
+            let n = samples.len();
+            let (last, diff, second) = (
+                samples[n - 1],
+                samples[n - 1] - samples[n - 2],
+                second[second.len() - 1],
+            );
+            let left = ((steps - step) / period) as i64;
+
+            return (last + left * diff + second * left * (left + 1) / 2) as usize;
+        }
+    }
+
+    unreachable!("the walk goes on forever")
+}
+
+/// Generates a map with the shape of the inputs: an odd size with the start in
+/// the middle, and an empty middle row and column, border and diamond between
+/// the middles of the sides.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = 5 + 2 * (size.min(100) / 4);
+    let middle = side / 2;
+
+    (0..side)
+        .map(|i| {
+            (0..side)
+                .map(|j| {
+                    let diamond = i.abs_diff(middle) + j.abs_diff(middle);
+
+                    if (i, j) == (middle, middle) {
+                        'S'
+                    } else if i == middle
+                        || j == middle
+                        || i == 0
+                        || j == 0
+                        || i == side - 1
+                        || j == side - 1
+                        || diamond.abs_diff(middle) <= 1
+                    {
+                        '.'
+                    } else if rng.gen_bool(0.15) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+#[test]
+fn test_part2() {
+    let cases: [(usize, usize); 7] = [
+        (6, 16),
+        (10, 50),
+        (50, 1594),
+        (100, 6536),
+        (500, 167004),
+        (1000, 668697),
+        (5000, 16733044),
+    ];
+
+    for (steps, expected) in cases {
+        let params = params::Params::from([("steps".to_string(), steps.to_string())]);
+        let result = params::with(&params, || part2(TEST_INPUT));
+        assert_eq!(Ok(expected.into()), result, "{steps} steps");
+    }
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = include_str!("../../examples/2023/day21/sample.txt");
+
+struct Map {
+    start: Pos,
+    tiles: Vec<Vec<char>>,
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Map> {
+        let tiles = util::non_empty_lines(s)
+            .map(|s| s.chars().collect_vec())
+            .collect_vec();
+
+        // The walks look up tiles by the number of rows in both directions.
+        if tiles.is_empty() || tiles.iter().any(|row| row.len() != tiles.len()) {
+            return Err("the map is not square".to_string());
+        }
+
+        let mut start = None;
+        for (i, r) in tiles.iter().enumerate() {
+            for (j, &c) in r.iter().enumerate() {
+                if c == 'S' {
+                    start = Some(Pos(i as i32, j as i32));
+                    break;
+                }
+            }
+        }
+
+        Ok(Map {
+            start: start.ok_or("there is no start tile S")?,
+            tiles,
+        })
+    }
+}
+
+impl Map {
+    fn walk(&self, steps_left: usize) -> usize {
+        self.walk_from(&self.start, steps_left)
+    }
+
+    fn walk_from(&self, pos: &Pos, steps_left: usize) -> usize {
+        let mut walk = Walk::new(self, *pos);
+        walk.walk(steps_left);
+
+        // If the number of steps is even we should count even tiles, otherwise
+        // we count the odd ones.
+        walk.reached[steps_left % 2]
+    }
+}
+
+/// Draws the plots the gardener can reach in the "steps" param without leaving
+/// the map. For part2 that is every plot of the same parity as the steps, as
+/// on the maps that are fully covered.
+pub fn render(input: &str, part: u32) -> Result<Image> {
+    let map: Map = input.parse()?;
+    let steps = params::get("steps", if part == 1 { 64 } else { 26501365 })?;
+
+    let mut walk = Walk::new(&map, map.start);
+    walk.walk(steps);
+
+    let size = map.tiles.len();
+    let mut image = Image::new(size, size, Color::WHITE);
+    for (y, row) in map.tiles.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if c == '#' {
+                image.set(x, y, Color::DARK);
+            }
+        }
+    }
+    for (&Pos(y, x), n) in walk.visited.iter() {
+        if n % 2 == steps % 2 {
+            image.set(x as usize, y as usize, Color::GREEN);
+        }
+    }
+
+    Ok(image)
+}
+
+pub fn simulation(input: &str) -> Result<Box<dyn Simulation>> {
+    let map: Map = input.parse()?;
+    Ok(Box::new(Walk::new(&map, map.start)))
+}
+
+/// A walk from a tile, one step at a time. The gardener can't step outside the
+/// map.
+#[derive(Clone)]
+struct Walk {
+    tiles: Vec<Vec<char>>,
+    steps: usize,
+    /// The number of steps to each tile that has been reached.
+    visited: HashMap<Pos, usize>,
+    /// The tiles first reached in the last step.
+    frontier: Vec<Pos>,
+    /// The number of tiles first reached after an even and an odd number of
+    /// steps.
+    reached: [usize; 2],
+}
+
+impl Walk {
+    fn new(map: &Map, start: Pos) -> Walk {
+        Walk {
+            tiles: map.tiles.clone(),
+            steps: 0,
+            visited: HashMap::from([(start, 0)]),
+            frontier: vec![start],
+            reached: [1, 0],
+        }
+    }
+
+    /// Takes up to `steps` steps, stopping early once no new tiles are reached.
+    fn walk(&mut self, steps: usize) {
+        for _ in 0..steps {
+            if self.finished() {
+                break;
+            }
+            self.advance();
+        }
+    }
+
+    fn advance(&mut self) {
+        self.steps += 1;
+
+        let size = self.tiles.len() as i32;
+        let mut frontier = Vec::new();
+
+        for &Pos(row, col) in self.frontier.iter() {
+            for (dy, dx) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let (i, j) = (row + dy, col + dx);
+
+                if i < 0 || j < 0 || i >= size || j >= size {
+                    continue;
+                }
+
+                if self.tiles[i as usize][j as usize] != '#'
+                    && !self.visited.contains_key(&Pos(i, j))
+                {
+                    self.visited.insert(Pos(i, j), self.steps);
+                    frontier.push(Pos(i, j));
+                }
+            }
+        }
+
+        self.reached[self.steps % 2] += frontier.len();
+        self.frontier = frontier;
+    }
+}
+
+impl Simulation for Walk {
+    fn step(&mut self) -> Result<()> {
+        self.advance();
+        Ok(())
+    }
+
+    fn finished(&self) -> bool {
+        self.frontier.is_empty()
+    }
+
+    /// Tiles are only ever added, so the number of them and the frontier tell
+    /// the states apart.
+    fn state(&self) -> State {
+        State::of(&(
+            self.visited.len(),
+            self.frontier.iter().sorted().collect_vec(),
+        ))
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new(self)
+    }
+
+    fn restore(&mut self, snapshot: &Snapshot) {
+        *self = snapshot.get();
+    }
+
+    /// Marks the tiles the gardener can be on after the steps so far with O.
+    fn render(&self) -> String {
+        let mut out = String::new();
+
+        for (i, row) in self.tiles.iter().enumerate() {
+            for (j, &c) in row.iter().enumerate() {
+                match self.visited.get(&Pos(i as i32, j as i32)) {
+                    Some(steps) if steps % 2 == self.steps % 2 => out.push('O'),
+                    _ => out.push(c),
+                }
+            }
+            out.push('\n');
+        }
+
+        out.push_str(&format!(
+            "steps: {}, reachable: {}\n",
+            self.steps,
+            self.reached[self.steps % 2]
+        ));
+        out
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Pos(i32, i32);
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
@@ -0,0 +1,344 @@
+use std::{collections::HashSet, str::FromStr};
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    simulation::{Simulation, Snapshot, State},
+    util,
+};
+
+extern crate itertools;
+extern crate num;
+
+pub fn part1(input: &str) -> Solution {
+    let mut stack: Stack = input.parse().map_err(Unsolved::Parse)?;
+    stack.settle();
+    let bricks = stack.bricks;
+
+    let mut count = 0;
+
+    for (id, _) in bricks.iter() {
+        let mut is_required = false;
+        for (_, b) in bricks.iter() {
+            if b.supported_by.contains(id) && b.supported_by.len() == 1 {
+                is_required = true;
+            }
+        }
+
+        if !is_required {
+            count += 1
+        }
+    }
+
+    Ok(count.into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let mut stack: Stack = input.parse().map_err(Unsolved::Parse)?;
+    stack.settle();
+    let bricks = stack.bricks;
+
+    let count = bricks
+        .iter()
+        .map(|(id, _)| find_falling_bricks(id, &bricks))
+        .sum::<usize>();
+
+    Ok(count.into())
+}
+
+#[derive(Clone, Debug)]
+struct Brick {
+    start: Pos,
+    stop: Pos,
+    supported_by: HashSet<usize>,
+}
+
+impl<'a> Brick {
+    fn collides_with(&self, other: &Self) -> bool {
+        let overlap_x = self.stop.x >= other.start.x && self.start.x <= other.stop.x;
+        let overlap_y = self.stop.y >= other.start.y && self.start.y <= other.stop.y;
+
+        overlap_x && overlap_y
+    }
+}
+
+impl<'a> FromStr for Brick {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Brick> {
+        let (s1, s2) = s.split_once("~").ok_or(format!("invalid brick: {s}"))?;
+        let (start, stop): (Pos, Pos) = (s1.parse()?, s2.parse()?);
+
+        if start.x > stop.x || start.y > stop.y || start.z > stop.z {
+            return Err(format!("the brick ends before it starts: {s}"));
+        }
+
+        Ok(Brick {
+            start,
+            stop,
+            supported_by: HashSet::new(),
+        })
+    }
+}
+
+fn find_falling_bricks(id: &usize, bricks: &[(usize, Brick)]) -> usize {
+    let mut disintegrated = HashSet::new();
+    disintegrated.insert(*id);
+
+    for (i, b) in bricks
+        .iter()
+        .sorted_by(|(_, a), (_, b)| a.start.z.cmp(&b.start.z))
+    {
+        if b.supported_by.intersection(&disintegrated).count() > 0
+            && b.supported_by.difference(&disintegrated).count() == 0
+        {
+            disintegrated.insert(*i);
+        }
+    }
+
+    disintegrated.len() - 1
+}
+
+#[derive(Copy, Clone, Debug, Hash)]
+struct Pos {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl FromStr for Pos {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Pos> {
+        // Small enough coordinates that moving the bricks down never
+        // overflows.
+        let (x, y, z) = s
+            .split(",")
+            .map(|c| c.parse::<u16>().ok().map(i32::from))
+            .collect::<Option<Vec<_>>>()
+            .and_then(|c| c.into_iter().collect_tuple())
+            .ok_or(format!("invalid position: {s}"))?;
+
+        Ok(Pos { x, y, z })
+    }
+}
+
+pub fn simulation(input: &str) -> Result<Box<dyn Simulation>> {
+    Ok(Box::new(input.parse::<Stack>()?))
+}
+
+/// The bricks falling into place, one brick per step from the lowest one up.
+#[derive(Clone)]
+struct Stack {
+    /// The bricks in the order they are listed, with their ids.
+    bricks: Vec<(usize, Brick)>,
+    /// The indices of the bricks in the order they fall.
+    order: Vec<usize>,
+    /// The number of bricks that have settled, the first ones in `order`.
+    fallen: usize,
+}
+
+impl FromStr for Stack {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Stack> {
+        let bricks = util::non_empty_lines(s)
+            .enumerate()
+            .map(|(i, s)| Ok((i, s.parse::<Brick>()?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let order = (0..bricks.len())
+            .sorted_by(|&a, &b| bricks[a].1.start.z.cmp(&bricks[b].1.start.z))
+            .collect_vec();
+
+        Ok(Stack {
+            bricks,
+            order,
+            fallen: 0,
+        })
+    }
+}
+
+impl Stack {
+    fn settle(&mut self) {
+        while !self.finished() {
+            self.advance();
+        }
+    }
+
+    /// Moves the next brick down until it rests on a settled brick or the
+    /// ground.
+    fn advance(&mut self) {
+        let index = self.order[self.fallen];
+        let b = &self.bricks[index].1;
+
+        let mut stop_z = None;
+        let mut supported_by = HashSet::new();
+
+        for &k in self.order[..self.fallen]
+            .iter()
+            .sorted_by(|&&a, &&b| self.bricks[a].1.stop.z.cmp(&self.bricks[b].1.stop.z))
+            .rev()
+        {
+            let (id, o) = &self.bricks[k];
+
+            if o.collides_with(b) {
+                if let Some(z) = stop_z {
+                    if o.stop.z == z {
+                        // We found another support on the same z-level as the
+                        // previous one.
+                        supported_by.insert(*id);
+                    } else {
+                        break;
+                    }
+                } else {
+                    stop_z = Some(o.stop.z);
+                    supported_by.insert(*id);
+                }
+            }
+        }
+
+        let b = &mut self.bricks[index].1;
+        b.supported_by.extend(supported_by);
+
+        let old_z = b.start.z;
+        b.start = Pos {
+            z: if let Some(z) = stop_z { z + 1 } else { 1 },
+            ..b.start
+        };
+        b.stop = Pos {
+            z: if let Some(z) = stop_z {
+                b.stop.z - (old_z - (z + 1))
+            } else {
+                b.stop.z - (old_z - 1)
+            },
+            ..b.stop
+        };
+
+        self.fallen += 1;
+    }
+}
+
+impl Simulation for Stack {
+    fn step(&mut self) -> Result<()> {
+        self.advance();
+        Ok(())
+    }
+
+    fn finished(&self) -> bool {
+        self.fallen == self.bricks.len()
+    }
+
+    fn state(&self) -> State {
+        let positions = self
+            .bricks
+            .iter()
+            .map(|(_, b)| (b.start, b.stop))
+            .collect_vec();
+
+        State::of(&(self.fallen, positions))
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new(self)
+    }
+
+    fn restore(&mut self, snapshot: &Snapshot) {
+        *self = snapshot.get();
+    }
+
+    /// Draws the bricks as seen from the front, x to the right and z up. The
+    /// settled bricks are #, the ones still falling o, and the ground at z 0
+    /// is -.
+    fn render(&self) -> String {
+        let width = self
+            .bricks
+            .iter()
+            .map(|(_, b)| b.stop.x + 1)
+            .max()
+            .unwrap_or(0);
+        let height = self.bricks.iter().map(|(_, b)| b.stop.z).max().unwrap_or(0);
+
+        let mut rows = vec![vec!['.'; width as usize]; height as usize + 1];
+        rows[0].fill('-');
+        for (k, &index) in self.order.iter().enumerate() {
+            let b = &self.bricks[index].1;
+            for (x, z) in (b.start.x..=b.stop.x).cartesian_product(b.start.z..=b.stop.z) {
+                let cell = &mut rows[z as usize][x as usize];
+                if k < self.fallen {
+                    *cell = '#';
+                } else if *cell != '#' {
+                    *cell = 'o';
+                }
+            }
+        }
+
+        let mut out = String::new();
+        for row in rows.iter().rev() {
+            out.extend(row);
+            out.push('\n');
+        }
+        out.push_str(&format!(
+            "settled: {} of {}\n",
+            self.fallen,
+            self.bricks.len()
+        ));
+
+        out
+    }
+}
+
+/// Generates bricks over a 10 by 10 area by dropping them one at a time to a
+/// random height above everything below them, so they never overlap.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut heights = [[0; 10]; 10];
+    let mut lines = Vec::new();
+
+    for _ in 0..1 + size.min(1_500) {
+        let length = rng.gen_range(0..=3);
+        let start = Pos {
+            x: rng.gen_range(0..10),
+            y: rng.gen_range(0..10),
+            z: 0,
+        };
+        let mut stop = start;
+        match rng.gen_range(0..3) {
+            0 => stop.x = (start.x + length).min(9),
+            1 => stop.y = (start.y + length).min(9),
+            _ => stop.z = length,
+        }
+
+        let cells = (start.x..=stop.x).cartesian_product(start.y..=stop.y);
+        let z = 1
+            + rng.gen_range(0..3)
+            + cells
+                .clone()
+                .map(|(x, y)| heights[x as usize][y as usize])
+                .max()
+                .unwrap();
+        for (x, y) in cells {
+            heights[x as usize][y as usize] = z + stop.z;
+        }
+
+        lines.push(format!(
+            "{},{},{z}~{},{},{}\n",
+            start.x,
+            start.y,
+            stop.x,
+            stop.y,
+            z + stop.z
+        ));
+    }
+    lines.shuffle(rng);
+
+    lines.concat()
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
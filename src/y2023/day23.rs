@@ -0,0 +1,389 @@
+use std::{
+    cmp::max,
+    collections::{HashMap, HashSet},
+};
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    checkpoint, progress,
+    render::{Color, Image},
+    util,
+};
+
+extern crate itertools;
+extern crate num;
+
+pub fn part1(input: &str) -> Solution {
+    let grid = parse_grid(input).map_err(Unsolved::Parse)?;
+
+    let start = (0i32, 1i32);
+    let end = ((grid.len() - 1) as i32, (grid[0].len() - 2) as i32);
+
+    let graph = build_graph(&grid, &start, &end, true);
+
+    longest_hike(&graph, &start, &end)
+}
+
+pub fn part2(input: &str) -> Solution {
+    let grid = parse_grid(input).map_err(Unsolved::Parse)?;
+
+    let start = (0i32, 1i32);
+    let end = ((grid.len() - 1) as i32, (grid[0].len() - 2) as i32);
+
+    let graph = build_graph(&grid, &start, &end, false);
+
+    longest_hike(&graph, &start, &end)
+}
+
+/// Draws the map with the longest hike over it.
+pub fn render(input: &str, part: u32) -> Result<Image> {
+    let grid = parse_grid(input)?;
+
+    let start = (0i32, 1i32);
+    let end = ((grid.len() - 1) as i32, (grid[0].len() - 2) as i32);
+
+    let graph = build_graph(&grid, &start, &end, part == 1);
+    let (_, junctions) = longest_trail(&graph, &start, &end, &mut HashSet::new())
+        .ok_or("there is no hike from the start to the end")?;
+
+    let mut image = Image::new(grid[0].len(), grid.len(), Color::WHITE);
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            match c {
+                '#' => image.set(x, y, Color::DARK),
+                '.' => {}
+                _ => image.set(x, y, Color::GRAY),
+            }
+        }
+    }
+    for pair in junctions.windows(2) {
+        let length = graph[&pair[0]][&pair[1]];
+        for (r, c) in trail(&grid, &graph, pair[0], pair[1], length) {
+            image.set(c as usize, r as usize, Color::BLUE);
+        }
+    }
+
+    Ok(image)
+}
+
+fn parse_grid(input: &str) -> Result<Vec<Vec<char>>> {
+    let grid = util::non_empty_lines(input)
+        .map(|s| s.chars().collect_vec())
+        .collect_vec();
+
+    // The start is in the second column, and the end in the second to last.
+    let width = grid.first().map_or(0, |row| row.len());
+    if width < 2 || grid.iter().any(|row| row.len() != width) {
+        return Err("the map is not a rectangle of at least two columns".to_string());
+    }
+
+    Ok(grid)
+}
+
+/// Dead ends are i32::MIN plus the steps taken, so a negative length means that
+/// no hike reaches the end.
+///
+/// The search is split into the hikes that start with the same junctions, and
+/// the progress is saved to the checkpoint after each of them.
+fn longest_hike(graph: &Graph, start: &Pos, end: &Pos) -> Solution {
+    let mut progress = checkpoint::resume()?.unwrap_or(Progress {
+        done: 0,
+        longest: i32::MIN,
+    });
+
+    let prefixes = hike_prefixes(graph, start, end);
+    progress::set_total(prefixes.len().saturating_sub(progress.done));
+    for (hike, steps) in prefixes.iter().skip(progress.done) {
+        let (last, before) = hike.split_last().unwrap();
+        let mut seen = before.iter().copied().collect();
+
+        progress.longest = max(progress.longest, steps + dfs(graph, last, end, &mut seen));
+        progress.done += 1;
+        progress::inc();
+        checkpoint::save(&progress)?;
+    }
+
+    if progress.longest < 0 {
+        return Err("there is no hike from the start to the end".into());
+    }
+
+    Ok(progress.longest.into())
+}
+
+/// The number of junctions the hikes of a part of the search start with.
+const PREFIX_JUNCTIONS: usize = 8;
+
+/// Returns the starts of the hikes up to PREFIX_JUNCTIONS junctions long, with
+/// their steps. Hikes that reach the end or a dead end sooner are shorter.
+/// Junctions are visited in order, so the parts are the same in every run.
+fn hike_prefixes(graph: &Graph, start: &Pos, end: &Pos) -> Vec<(Vec<Pos>, i32)> {
+    let mut prefixes = Vec::new();
+    let mut stack = vec![(vec![*start], 0)];
+
+    while let Some((hike, steps)) = stack.pop() {
+        let p = hike.last().unwrap();
+        let next = graph[p]
+            .iter()
+            .filter(|(next, _)| !hike.contains(next))
+            .sorted()
+            .collect_vec();
+
+        if p == end || hike.len() == PREFIX_JUNCTIONS || next.is_empty() {
+            prefixes.push((hike, steps));
+            continue;
+        }
+
+        for (&next, &n) in next.into_iter().rev() {
+            let mut longer = hike.clone();
+            longer.push(next);
+            stack.push((longer, steps + n));
+        }
+    }
+
+    prefixes
+}
+
+struct Progress {
+    /// The number of prefixes searched.
+    done: usize,
+    longest: i32,
+}
+
+impl checkpoint::State for Progress {
+    fn encode(&self) -> String {
+        format!("{} {}", self.done, self.longest)
+    }
+
+    fn decode(s: &str) -> Result<Progress> {
+        let [done, longest]: [i64; 2] = checkpoint::numbers(s)?;
+
+        Ok(Progress {
+            done: done as usize,
+            longest: longest as i32,
+        })
+    }
+}
+
+type Pos = (i32, i32);
+type Graph = HashMap<Pos, HashMap<Pos, i32>>;
+
+fn build_graph(grid: &Vec<Vec<char>>, start: &Pos, end: &Pos, slippery_slopes: bool) -> Graph {
+    let height = grid.len() as i32;
+    let width = grid[0].len() as i32;
+
+    let mut points = vec![*start, *end];
+
+    // Find all intersection points
+    for (r, row) in grid.iter().enumerate() {
+        for (c, &ch) in row.iter().enumerate() {
+            if ch == '#' {
+                continue;
+            }
+
+            let mut neighbors = 0;
+            for (next_row, next_col) in [
+                (r as i32 - 1, c as i32),
+                (r as i32 + 1, c as i32),
+                (r as i32, c as i32 - 1),
+                (r as i32, c as i32 + 1),
+            ] {
+                if next_row >= 0
+                    && next_row < height
+                    && next_col >= 0
+                    && next_col < width
+                    && grid[next_row as usize][next_col as usize] != '#'
+                {
+                    neighbors += 1;
+                }
+            }
+            if neighbors >= 3 {
+                points.push((r as i32, c as i32))
+            }
+        }
+    }
+
+    let mut graph: Graph = points.iter().map(|&p| (p, HashMap::new())).collect();
+
+    // Build the adjacency list for the intersection points.
+    for &(start_row, start_col) in points.iter() {
+        let mut stack = vec![(0, start_row, start_col)];
+        let mut seen = HashSet::new();
+        seen.insert((start_row, start_col));
+
+        while let Some((n, r, c)) = stack.pop() {
+            if n != 0 && points.contains(&(r, c)) {
+                graph
+                    .get_mut(&(start_row, start_col))
+                    .unwrap()
+                    .insert((r, c), n);
+                continue;
+            }
+
+            for (dr, dc) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                match grid[r as usize][c as usize] {
+                    '#' => continue,
+                    '^' if slippery_slopes && (dr, dc) != (-1, 0) => continue,
+                    'v' if slippery_slopes && (dr, dc) != (1, 0) => continue,
+                    '<' if slippery_slopes && (dr, dc) != (0, -1) => continue,
+                    '>' if slippery_slopes && (dr, dc) != (0, 1) => continue,
+                    _ => {}
+                }
+
+                let next_row = r + dr;
+                let next_col = c + dc;
+
+                if seen.contains(&(next_row, next_col)) {
+                    continue;
+                }
+
+                if next_row >= 0 && next_row < height && next_col >= 0 && next_col < width {
+                    stack.push((n + 1, next_row, next_col));
+                    seen.insert((next_row, next_col));
+                }
+            }
+        }
+    }
+
+    graph
+}
+
+fn dfs(graph: &Graph, p: &Pos, end: &Pos, seen: &mut HashSet<Pos>) -> i32 {
+    if p == end {
+        return 0;
+    }
+
+    let mut m = i32::MIN;
+
+    seen.insert(*p);
+    for (next, n) in graph.get(p).unwrap().iter() {
+        if !seen.contains(next) {
+            m = max(m, n + dfs(graph, next, end, seen));
+        }
+    }
+    seen.remove(p);
+
+    m
+}
+
+/// Like dfs, but also returns the junctions along the longest hike. None means
+/// that no hike reaches the end.
+fn longest_trail(
+    graph: &Graph,
+    p: &Pos,
+    end: &Pos,
+    seen: &mut HashSet<Pos>,
+) -> Option<(i32, Vec<Pos>)> {
+    if p == end {
+        return Some((0, vec![*p]));
+    }
+
+    let mut best: Option<(i32, Vec<Pos>)> = None;
+
+    seen.insert(*p);
+    for (next, n) in graph.get(p).unwrap().iter() {
+        if seen.contains(next) {
+            continue;
+        }
+        if let Some((steps, trail)) = longest_trail(graph, next, end, seen) {
+            if best.as_ref().is_none_or(|(m, _)| n + steps > *m) {
+                best = Some((n + steps, trail));
+            }
+        }
+    }
+    seen.remove(p);
+
+    best.map(|(steps, mut trail)| {
+        trail.insert(0, *p);
+        (steps, trail)
+    })
+}
+
+/// Returns the tiles of the path of `length` steps from junction `a` to
+/// junction `b`. Between junctions the paths never branch, so they can be
+/// followed one tile at a time.
+fn trail(grid: &[Vec<char>], graph: &Graph, a: Pos, b: Pos, length: i32) -> Vec<Pos> {
+    let open = |&(r, c): &Pos| {
+        r >= 0
+            && c >= 0
+            && (r as usize) < grid.len()
+            && (c as usize) < grid[0].len()
+            && grid[r as usize][c as usize] != '#'
+    };
+    let neighbors = |(r, c): Pos| [(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)];
+
+    for first in neighbors(a).into_iter().filter(open) {
+        let mut tiles = vec![a, first];
+        let (mut previous, mut current) = (a, first);
+
+        while !graph.contains_key(&current) {
+            let Some(next) = neighbors(current)
+                .into_iter()
+                .filter(open)
+                .find(|&p| p != previous)
+            else {
+                break;
+            };
+
+            tiles.push(next);
+            (previous, current) = (current, next);
+        }
+
+        if current == b && tiles.len() as i32 - 1 == length {
+            return tiles;
+        }
+    }
+
+    vec![a, b]
+}
+
+/// Generates a maze with the shape of the inputs: a square lattice of
+/// junctions joined by straight paths, with slopes next to the junctions that
+/// only lead right or down. Some paths are left out, but never the ones along
+/// the top and the right, so the end can always be reached.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let junctions = 2 + size.min(40) / 10;
+    let spacing = rng.gen_range(4..=6);
+    let side = 2 + spacing * (junctions - 1) + 3;
+
+    let mut grid = vec![vec!['#'; side]; side];
+    let at = |i: usize| 2 + spacing * i;
+
+    // The entrance and the exit lead to the first and the last junction.
+    for (r, c) in [(0, 1), (1, 1), (2, 1)] {
+        grid[r][c] = '.';
+        grid[side - 1 - r][side - 1 - c] = '.';
+    }
+
+    for i in 0..junctions {
+        for j in 0..junctions {
+            grid[at(i)][at(j)] = '.';
+
+            if j + 1 < junctions && (i == 0 || rng.gen_bool(0.8)) {
+                grid[at(i)][at(j) + 1..at(j + 1)].fill('.');
+                grid[at(i)][at(j) + 1] = '>';
+                grid[at(i)][at(j + 1) - 1] = '>';
+            }
+            if i + 1 < junctions && (j == junctions - 1 || rng.gen_bool(0.8)) {
+                for row in grid[at(i) + 1..at(i + 1)].iter_mut() {
+                    row[at(j)] = '.';
+                }
+                grid[at(i) + 1][at(j)] = 'v';
+                grid[at(i + 1) - 1][at(j)] = 'v';
+            }
+        }
+    }
+
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
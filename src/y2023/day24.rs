@@ -0,0 +1,297 @@
+use std::fmt::Debug;
+
+use itertools::Itertools;
+use num::{BigInt, BigRational, Zero};
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    util,
+};
+
+extern crate itertools;
+extern crate num;
+
+pub fn part1(input: &str) -> Solution {
+    let area = (200000000000000.0, 400000000000000.0);
+
+    Ok(count_intersections(input, area)
+        .map_err(Unsolved::Parse)?
+        .into())
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(Ok(2), count_intersections(TEST_INPUT, (7.0, 27.0)));
+}
+
+pub fn part2(input: &str) -> Solution {
+    let hailstones = util::non_empty_lines(input)
+        .map(|s| Hailstone::from(&s))
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
+
+    // The rock hits every hailstone, so for each hailstone there is a time t
+    // where p + t * v = p_hs + t * v_hs. This means that (p - p_hs) and
+    // (v - v_hs) are parallel, and their cross product is zero:
+    //
+    //     (p - p_hs) x (v - v_hs) = 0
+    //
+    // Expanding this gives a non-linear p x v term, but it is the same for all
+    // hailstones. Subtracting the equations of two hailstones i and j removes
+    // it and leaves three linear equations:
+    //
+    //     p x (v_i - v_j) + (p_i - p_j) x v = p_i x v_i - p_j x v_j
+    //
+    // Using two pairs of hailstones gives six equations for the six unknowns.
+    for (h0, h1, h2) in hailstones.iter().tuple_combinations() {
+        let mut equations = rock_equations(h0, h1);
+        equations.extend(rock_equations(h0, h2));
+
+        if let Some(rock) = solve_linear(equations) {
+            let sum = &rock[0] + &rock[1] + &rock[2];
+            if !sum.is_integer() {
+                return Err(format!("the rock starts at a fractional position: {sum}").into());
+            }
+
+            return Ok(sum.to_integer().into());
+        }
+    }
+
+    Err("no rock trajectory hits all the hailstones".into())
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = include_str!("../../examples/2023/day24/sample.txt");
+
+struct Hailstone {
+    s: Pos,
+    v: Vel,
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl Debug for Hailstone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hailstone")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .field("c", &self.c)
+            .finish()
+    }
+}
+
+impl Hailstone {
+    fn int_pos(&self) -> [i128; 3] {
+        [self.s.0 as i128, self.s.1 as i128, self.s.2 as i128]
+    }
+
+    fn int_vel(&self) -> [i128; 3] {
+        [self.v.0 as i128, self.v.1 as i128, self.v.2 as i128]
+    }
+
+    fn from(s: &str) -> Result<Hailstone> {
+        let invalid = || format!("invalid hailstone: {s}");
+
+        let parse = |v: &str| {
+            v.split(",")
+                .map(|c| parse_coordinate(c.trim()))
+                .collect::<Option<Vec<_>>>()
+                .and_then(|c| c.into_iter().collect_tuple())
+                .ok_or_else(invalid)
+        };
+
+        let (pos, vel) = s.split_once(" @ ").ok_or_else(invalid)?;
+        let (sx, sy, sz) = parse(pos)?;
+        let (vx, vy, vz) = parse(vel)?;
+
+        Ok(Hailstone {
+            s: (sx, sy, sz),
+            v: (vx, vy, vz),
+            a: vy,
+            b: -vx,
+            c: vy * sx - vx * sy,
+        })
+    }
+}
+
+/// Small enough that the coordinates are exact as floats, and that their cross
+/// products fit in an i128.
+fn parse_coordinate(c: &str) -> Option<f64> {
+    let c = c.parse::<i64>().ok()?;
+
+    (c.unsigned_abs() <= 1 << 50).then_some(c as f64)
+}
+
+fn count_intersections(input: &str, area: (f64, f64)) -> Result<usize> {
+    let hailstones = util::non_empty_lines(input)
+        .map(|s| Hailstone::from(&s))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut count = 0;
+
+    for (i, s) in hailstones.iter().enumerate() {
+        for t in hailstones.iter().skip(i) {
+            let (a1, b1, c1) = (s.a, s.b, s.c);
+            let (a2, b2, c2) = (t.a, t.b, t.c);
+
+            // Ignore parallel hailstones.
+            if a1 * b2 == b1 * a2 {
+                continue;
+            }
+
+            // Find the intersection point of the lines.
+            let x = (c1 * b2 - c2 * b1) / (a1 * b2 - a2 * b1);
+            let y = (c2 * a1 - c1 * a2) / (a1 * b2 - a2 * b1);
+
+            // Ignore intersections that happen in the past.
+            if [s, t]
+                .iter()
+                .any(|hs| (x - hs.s.0) * hs.v.0 < 0.0 && (y - hs.s.1) * hs.v.1 < 0.0)
+            {
+                continue;
+            }
+
+            // Ignore intersections outside the test area.
+            if x < area.0 || x > area.1 || y < area.0 || y > area.1 {
+                continue;
+            }
+
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+// x_r + t * vx_r
+// x_h + t * vx_h
+//
+// x_r + vx_r * t = x_h + vx_h * t     =>
+//
+//           t = (x_r - x_h)/(vx_h - vx_r)   =   (y_r - y_h) / (vy_h - vy_r)   =   (z_r - z_h) / (vz_h - vz_r)
+//
+//
+//             (px_r - px_h) * (vy_h - vy_r) = (py_r - py_h) * (vx_h - vx_r)
+//             (py_r - py_h) * (vz_h - vz_r) = (pz_r - pz_h) * (vy_h - vy_r)
+//             (pz_r - pz_h) * (vz_h - vz_r) = (px_r - pz_h) * (vx_h - vx_r)
+//
+//             (px_r - px_h) * (vy_h - vy_r) - (py_r - py_h) * (vx_h - vx_r) = 0
+//             (py_r - py_h) * (vz_h - vz_r) - (pz_r - pz_h) * (vy_h - vy_r) = 0
+//             (pz_r - pz_h) * (vx_h - vx_r) - (px_r - px_h) * (vz_h - vz_r) = 0
+//
+//             px_r * vy_h - px_r * vy_r  + px_h * vy_r - py_r * vx_h + py_r * vx_r - py_h * vx_r + py_h * vx_h - px_h * vy_h = 0
+//
+// A * p_r + B * v_r + C = 0
+//
+//
+// (pz_r - 236728636905923) * (-44 + 261) - (420851642592931 - 359781776524153) * (18 - vz_r) = 0
+// (pz_r - 273768862611813) * (35 + 261) - (420851642592931 - 276481733510955) * (33 - vz_r) = 0
+//
+//
+
+/// Returns the three equations for the rock given by the hailstones `a` and `b`.
+/// Each equation has the coefficients for (px, py, pz, vx, vy, vz) followed by
+/// the constant on the right hand side.
+fn rock_equations(a: &Hailstone, b: &Hailstone) -> Vec<Vec<BigRational>> {
+    let (pa, va) = (a.int_pos(), a.int_vel());
+    let (pb, vb) = (b.int_pos(), b.int_vel());
+
+    let dv = [va[0] - vb[0], va[1] - vb[1], va[2] - vb[2]];
+    let dp = [pa[0] - pb[0], pa[1] - pb[1], pa[2] - pb[2]];
+
+    let ca = cross(pa, va);
+    let cb = cross(pb, vb);
+    let c = [ca[0] - cb[0], ca[1] - cb[1], ca[2] - cb[2]];
+
+    [
+        [0, dv[2], -dv[1], 0, -dp[2], dp[1], c[0]],
+        [-dv[2], 0, dv[0], dp[2], 0, -dp[0], c[1]],
+        [dv[1], -dv[0], 0, -dp[1], dp[0], 0, c[2]],
+    ]
+    .iter()
+    .map(|row| {
+        row.iter()
+            .map(|&v| BigRational::from_integer(BigInt::from(v)))
+            .collect_vec()
+    })
+    .collect_vec()
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Solves the system of linear equations using Gaussian elimination. Returns
+/// None if the system does not have a unique solution.
+fn solve_linear(mut rows: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let n = rows.len();
+
+    for col in 0..n {
+        let pivot = (col..n).find(|&r| !rows[r][col].is_zero())?;
+        rows.swap(col, pivot);
+
+        for r in 0..n {
+            if r == col || rows[r][col].is_zero() {
+                continue;
+            }
+
+            let factor = &rows[r][col] / &rows[col][col];
+            let pivot_row = rows[col].clone();
+            for (v, p) in rows[r].iter_mut().zip(pivot_row.iter()).skip(col) {
+                *v -= &factor * p;
+            }
+        }
+    }
+
+    Some((0..n).map(|r| &rows[r][n] / &rows[r][r]).collect())
+}
+
+type Pos = (f64, f64, f64);
+type Vel = (f64, f64, f64);
+
+/// Generates hailstones that are all hit by a rock thrown from a random
+/// position, at the scale of the inputs so part 1's test area applies.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let position: [i64; 3] =
+        [(); 3].map(|_| rng.gen_range(100_000_000_000_000..300_000_000_000_000));
+    let velocity: [i64; 3] = [(); 3].map(|_| rng.gen_range(-300..=300));
+
+    let mut times = Vec::new();
+    while times.len() < 3 + size.min(300) {
+        let t = rng.gen_range(10_000_000_000..1_000_000_000_000i64);
+        if !times.contains(&t) {
+            times.push(t);
+        }
+    }
+
+    times
+        .iter()
+        .map(|&t| {
+            let v = loop {
+                let v = [(); 3].map(|_| rng.gen_range(-300..=300));
+                if v != velocity {
+                    break v;
+                }
+            };
+            let p = [0, 1, 2].map(|i| position[i] + t * (velocity[i] - v[i]));
+
+            format!(
+                "{}, {}, {} @ {}, {}, {}\n",
+                p[0], p[1], p[2], v[0], v[1], v[2]
+            )
+        })
+        .collect()
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
@@ -0,0 +1,270 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use itertools::Itertools;
+
+use crate::{
+    answer::{Solution, Unsolved},
+    checkpoint, progress, rng, trace,
+    util::{self},
+};
+
+extern crate itertools;
+extern crate num;
+
+pub fn part1(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input).collect_vec();
+    let graph = parse_graph(&lines).map_err(Unsolved::Parse)?;
+
+    // This uses Karger's Algorithm to find a minimal cut.
+    //
+    // Since this is a Monte Carlo algorithm, it will sometimes not find the
+    // most optimal cut, which means we might have to run it a few times to
+    // find a cut that only removes 3 edges. The random numbers come from the
+    // seeded generator, so a run can be replayed with the same seed.
+    //
+    // Each attempt has a seed of its own, drawn by the attempt before, so the
+    // retries can be picked up from a checkpoint.
+    let mut progress = match checkpoint::resume()? {
+        Some(progress) => progress,
+        None => Progress {
+            attempts: 0,
+            seed: rng::draw(|rng| rng.gen()),
+        },
+    };
+
+    let contracted = loop {
+        if progress.attempts >= MAX_ATTEMPTS {
+            return Err(format!("no cut of three wires in {MAX_ATTEMPTS} attempts").into());
+        }
+
+        let mut rng = StdRng::seed_from_u64(progress.seed);
+        let contracted = karger_min_cut(&graph, &mut rng);
+        progress::inc();
+        progress = Progress {
+            attempts: progress.attempts + 1,
+            seed: rng.gen(),
+        };
+
+        // No cut has fewer wires than the minimal one.
+        match contracted.edges.len() {
+            3 => break contracted,
+            edges if edges < 3 => {
+                return Err(format!("the components are split by cutting {edges} wires").into());
+            }
+            edges => trace::emit!("retry", edges = edges),
+        }
+
+        checkpoint::save(&progress)?;
+    };
+
+    let score = contracted
+        .vertices
+        .iter()
+        .fold(1, |acc, v| v.split(",").count() * acc);
+
+    Ok(score.into())
+}
+
+pub fn part2(_input: &str) -> Solution {
+    Err("there is no part 2 on day 25".into())
+}
+
+/// Far more attempts than it takes to find the cut of the inputs, but it does
+/// not exist in every graph.
+const MAX_ATTEMPTS: usize = 1_000;
+
+struct Progress {
+    attempts: usize,
+    /// The seed of the next attempt.
+    seed: u64,
+}
+
+impl checkpoint::State for Progress {
+    fn encode(&self) -> String {
+        format!("{} {}", self.attempts, self.seed)
+    }
+
+    fn decode(s: &str) -> Result<Progress> {
+        let [attempts, seed]: [u64; 2] = checkpoint::numbers(s)?;
+
+        Ok(Progress {
+            attempts: attempts as usize,
+            seed,
+        })
+    }
+}
+
+fn parse_graph<'a>(lines: &Vec<String>) -> Result<Graph> {
+    let mut vertices = HashSet::new();
+    // The order of the edges decides which one a random number picks, so it
+    // must not depend on the hashing of the set.
+    let mut edges = BTreeSet::new();
+
+    for line in lines {
+        let (from, dsts) = line
+            .split_once(": ")
+            .ok_or(format!("invalid component: {line}"))?;
+
+        vertices.insert(from.to_string());
+        for d in dsts.split(" ") {
+            vertices.insert(d.to_string());
+            edges.insert(Edge::new(from, d));
+        }
+    }
+
+    Ok(Graph {
+        vertices: HashSet::from_iter(vertices),
+        edges: Vec::from_iter(edges),
+    })
+}
+
+// a class to represent a unweighted edge in graph
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct Edge {
+    src: String,
+    dst: String,
+}
+
+impl Edge {
+    fn new<'a>(src: &str, dst: &str) -> Edge {
+        if src > dst {
+            Edge {
+                src: dst.to_string(),
+                dst: src.to_string(),
+            }
+        } else {
+            Edge {
+                src: src.to_string(),
+                dst: dst.to_string(),
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Graph {
+    vertices: HashSet<String>,
+    edges: Vec<Edge>,
+}
+
+fn karger_min_cut(graph: &Graph, rng: &mut StdRng) -> Graph {
+    let mut graph = graph.clone();
+
+    // A graph that falls apart runs out of edges first.
+    while graph.vertices.len() > 2 && !graph.edges.is_empty() {
+        // Choose a random edge to contract.
+        let i = rng.gen_range(0..graph.edges.len());
+        let edge = &graph.edges[i].clone();
+
+        // Create a new super node.
+        let super_node = format!("{},{}", edge.src, edge.dst);
+        graph.vertices.insert(super_node.clone());
+
+        // Remove the old nodes.
+        graph.vertices.remove(&edge.src);
+        graph.vertices.remove(&edge.dst);
+
+        graph.edges = graph
+            .edges
+            .iter_mut()
+            // Redirect all edges to the new super node.
+            .map(|e| {
+                if e.src == edge.src || e.src == edge.dst {
+                    e.src = super_node.clone();
+                }
+                if e.dst == edge.src || e.dst == edge.dst {
+                    e.dst = super_node.clone();
+                }
+
+                e.clone()
+            })
+            // Eliminate any self references.
+            .filter(|e| e.src != e.dst)
+            .collect_vec();
+    }
+
+    graph
+}
+
+/// Generates two groups of components joined by exactly three wires. Within a
+/// group each component is wired to at least four others, so the three wires
+/// are the only way to cut the groups apart.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut names = HashSet::new();
+    let mut name = |rng: &mut StdRng| loop {
+        let name = (0..3)
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect::<String>();
+        if names.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    let mut groups = Vec::new();
+    let mut edges = Vec::new();
+    for _ in 0..2 {
+        let group = (0..rng.gen_range(5..=5 + size.min(100) / 2))
+            .map(|_| name(rng))
+            .collect_vec();
+        let n = group.len();
+
+        for i in 0..n {
+            edges.push((group[i].clone(), group[(i + 1) % n].clone()));
+            edges.push((group[i].clone(), group[(i + 2) % n].clone()));
+        }
+        for _ in 0..n / 2 {
+            let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            let (a, b) = (a.min(b), a.max(b));
+            if a.abs_diff(b) > 2 && a.abs_diff(b) < n - 2 {
+                edges.push((group[a].clone(), group[b].clone()));
+            }
+        }
+
+        groups.push(group);
+    }
+
+    let mut cut = groups
+        .iter()
+        .map(|g| g.choose_multiple(rng, 3).cloned().collect_vec());
+    let (left, right) = (cut.next().unwrap(), cut.next().unwrap());
+    edges.extend(left.into_iter().zip(right));
+
+    // Each wire is listed once, under either of its components.
+    let mut lines = BTreeMap::<String, Vec<String>>::new();
+    for (a, b) in edges.into_iter().unique() {
+        let (from, to) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        lines.entry(from).or_default().push(to);
+    }
+
+    let mut lines = lines
+        .iter()
+        .map(|(from, to)| format!("{from}: {}\n", to.join(" ")))
+        .collect_vec();
+    lines.shuffle(rng);
+
+    lines.concat()
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
+
+#[test]
+fn test_part1_resumed_past_max_attempts() {
+    let path = std::env::temp_dir().join(format!("aoc-day25-{}", std::process::id()));
+    std::fs::write(&path, "aoc checkpoint\nday25\n1500 7").unwrap();
+
+    let config = checkpoint::Config::new(path.clone(), true);
+    let input = std::fs::read_to_string("examples/2023/day25/sample.txt").unwrap();
+    let answer = checkpoint::with(&config, "day25", || part1(&input));
+    assert_eq!(
+        Err(format!("no cut of three wires in {MAX_ATTEMPTS} attempts").into()),
+        answer
+    );
+
+    std::fs::remove_file(path).unwrap();
+}
@@ -2,32 +2,43 @@ extern crate itertools;
 
 use std::collections::HashMap;
 
-use crate::util;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
 
-pub fn part1(input: &str) -> Result<String> {
+use crate::{
+    answer::{Solution, Unsolved},
+    util,
+};
+
+pub fn part1(input: &str) -> Solution {
     let lines = util::non_empty_lines(input).collect::<Vec<_>>();
 
     let board = parse_input(lines);
 
-    let part_numbers = board.part_numbers();
+    let part_numbers = board.part_numbers().map_err(Unsolved::Parse)?;
+
+    let score = part_numbers.numbers.iter().map(|&n| n as u64).sum::<u64>();
 
-    Ok(format!("{}", part_numbers.numbers.iter().sum::<u32>()))
+    Ok(score.into())
 }
 
-pub fn part2(input: &str) -> Result<String> {
+pub fn part2(input: &str) -> Solution {
     let lines = util::non_empty_lines(input).collect::<Vec<_>>();
 
     let board = parse_input(lines);
-    let part_numbers = board.part_numbers();
+    let part_numbers = board.part_numbers().map_err(Unsolved::Parse)?;
 
-    let score: u32 = part_numbers
+    let score = part_numbers
         .gears
         .values()
         .filter(|vs| vs.len() > 1)
-        .map(|vs| vs.iter().fold(1, |acc, v| acc * v))
-        .sum();
-
-    Ok(format!("{}", score))
+        .map(|vs| {
+            vs.iter()
+                .try_fold(1u64, |acc, &v| acc.checked_mul(v as u64))
+                .ok_or("the gear ratio is too large".to_string())
+        })
+        .sum::<Result<u64>>()?;
+
+    Ok(score.into())
 }
 
 fn parse_input(lines: Vec<String>) -> Board {
@@ -50,7 +61,7 @@ struct Board {
 }
 
 impl Board {
-    pub fn part_numbers(&self) -> PartNumbers {
+    pub fn part_numbers(&self) -> Result<PartNumbers> {
         let mut part_numbers = PartNumbers {
             numbers: Vec::new(),
             gears: HashMap::new(),
@@ -73,7 +84,7 @@ impl Board {
                     curr_nbr.push(c);
                 } else {
                     if curr_nbr.len() > 0 {
-                        let nbr = curr_nbr.parse().unwrap();
+                        let nbr = parse_number(&curr_nbr)?;
 
                         match symbol {
                             Symbol::Gear { pos } => {
@@ -97,7 +108,7 @@ impl Board {
             }
 
             if curr_nbr.len() > 0 {
-                let nbr = curr_nbr.parse().unwrap();
+                let nbr = parse_number(&curr_nbr)?;
 
                 match symbol {
                     Symbol::Gear { pos } => {
@@ -116,7 +127,7 @@ impl Board {
             }
         }
 
-        return part_numbers;
+        return Ok(part_numbers);
     }
 
     fn check_for_symbol(&self, x: i32, y: i32) -> Symbol {
@@ -150,6 +161,11 @@ impl Board {
     }
 }
 
+fn parse_number(s: &str) -> Result<u32> {
+    s.parse()
+        .map_err(|_| format!("the number {s} is too large"))
+}
+
 enum Symbol {
     None,
     Gear { pos: (usize, usize) },
@@ -161,33 +177,46 @@ struct PartNumbers {
     gears: HashMap<(usize, usize), Vec<u32>>,
 }
 
+/// Generates a schematic of numbers of up to three digits and symbols, with
+/// some of the symbols gears next to one or two numbers.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+    let side = 3 + size.min(200);
+    let mut rows = vec![vec![b'.'; side]; side];
+
+    for row in rows.iter_mut() {
+        let mut x = rng.gen_range(0..3);
+        while x < side {
+            if rng.gen_bool(0.3) {
+                let len = rng.gen_range(1..=3).min(side - x);
+                for c in row[x..x + len].iter_mut() {
+                    *c = rng.gen_range(b'0'..=b'9');
+                }
+                x += len;
+            } else if rng.gen_bool(0.15) {
+                row[x] = if rng.gen_bool(0.5) {
+                    b'*'
+                } else {
+                    *SYMBOLS.choose(rng).unwrap()
+                };
+                x += 1;
+            }
+            x += 1 + rng.gen_range(0..3);
+        }
+    }
+
+    // Make sure there is at least one number next to a symbol.
+    rows[0][..2].copy_from_slice(b"1*");
+
+    rows.into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
+
 // -------------------------------------
 
 type Error = String;
 type Result<T> = std::result::Result<T, Error>;
 
 // -------------------------------------
-
-#[test]
-fn test_part1() {
-    assert_eq!("4361", part1(TEST_INPUT).unwrap());
-}
-
-#[test]
-fn test_part2() {
-    assert_eq!("467835", part2(TEST_INPUT).unwrap());
-}
-
-#[cfg(test)]
-const TEST_INPUT: &str = "
-467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..
-";
@@ -0,0 +1,156 @@
+extern crate itertools;
+
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    util,
+};
+
+pub fn part1(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input).collect::<Vec<_>>();
+
+    let cards = lines
+        .iter()
+        .map(parse_card)
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
+
+    let score = cards.iter().map(check_score).sum::<Result<u64>>()?;
+
+    Ok(score.into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input).collect::<Vec<_>>();
+
+    let cards = lines
+        .iter()
+        .map(parse_card)
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
+    let mut copies = HashMap::<usize, u64>::new();
+
+    let mut card_count: u64 = 0;
+
+    for i in 0..cards.len() {
+        let card = &cards[i];
+        let score = winning_nbrs(card);
+        let multiplier = *copies.get(&i).unwrap_or(&1);
+
+        for j in i + 1..i + 1 + score as usize {
+            let e = copies.entry(j).or_insert(1);
+            *e = e.checked_add(multiplier).ok_or_else(too_many)?;
+        }
+
+        card_count = card_count.checked_add(multiplier).ok_or_else(too_many)?;
+    }
+
+    Ok(card_count.into())
+}
+
+struct Card {
+    winning_nbrs: HashSet<u32>,
+    nbrs: Vec<u32>,
+}
+
+fn parse_card(s: &String) -> Result<Card> {
+    let (_, numbers) = s
+        .split_once(":")
+        .ok_or_else(|| format!("invalid card: {s}"))?;
+
+    let (first, second) = numbers
+        .split_once("|")
+        .ok_or_else(|| format!("invalid card: {s}"))?;
+
+    Ok(Card {
+        winning_nbrs: parse_nbrs(first).collect::<Result<_>>()?,
+        nbrs: parse_nbrs(second).collect::<Result<_>>()?,
+    })
+}
+
+fn parse_nbrs(s: &str) -> impl Iterator<Item = Result<u32>> + '_ {
+    s.trim()
+        .split(" ")
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().map_err(|_| format!("invalid number: {s}")))
+}
+
+fn too_many() -> Error {
+    "there are too many copies to count".to_string()
+}
+
+fn check_score(c: &Card) -> Result<u64> {
+    let mut score: u64 = 0;
+    for n in c.nbrs.iter() {
+        if c.winning_nbrs.contains(n) {
+            score = if score == 0 {
+                1
+            } else {
+                score
+                    .checked_mul(2)
+                    .ok_or("the card is worth too many points")?
+            }
+        }
+    }
+
+    return Ok(score);
+}
+
+fn winning_nbrs(c: &Card) -> usize {
+    c.nbrs.iter().filter(|n| c.winning_nbrs.contains(n)).count()
+}
+
+/// Generates cards with five winning numbers and eight numbers. The cards
+/// never win copies past the last card, and the number of copies is kept
+/// small enough to count.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    const MAX_COPIES: u64 = 1_000_000;
+
+    let count = 1 + size.min(200);
+    let mut copies = vec![1u64; count];
+    let mut out = String::new();
+
+    for i in 0..count {
+        let mut wins = rng.gen_range(0..=5).min(count - 1 - i);
+        if copies[i + 1..i + 1 + wins]
+            .iter()
+            .any(|&c| c + copies[i] > MAX_COPIES)
+        {
+            wins = 0;
+        }
+        for j in i + 1..i + 1 + wins {
+            copies[j] += copies[i];
+        }
+
+        let mut numbers = (1..100).collect_vec();
+        numbers.shuffle(rng);
+        let (winning, rest) = numbers.split_at(5);
+        let mut nbrs = winning[..wins]
+            .iter()
+            .chain(&rest[..8 - wins])
+            .collect_vec();
+        nbrs.shuffle(rng);
+
+        let format = |ns: &[&u32]| ns.iter().map(|n| format!("{n:>2}")).join(" ");
+        out.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            i + 1,
+            format(&winning.iter().collect_vec()),
+            format(&nbrs)
+        ));
+    }
+
+    out
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
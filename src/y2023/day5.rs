@@ -0,0 +1,364 @@
+extern crate itertools;
+
+use std::ops::Range;
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::answer::{Solution, Unsolved};
+
+pub fn part1(input: &str) -> Solution {
+    let almanac = parse(input).map_err(Unsolved::Parse)?;
+
+    let lowest = almanac
+        .seeds
+        .iter()
+        .map(|&seed| almanac.location(seed))
+        .min()
+        .unwrap();
+
+    Ok(lowest.into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let almanac = parse(input).map_err(Unsolved::Parse)?;
+
+    let loc_ranges = almanac
+        .seed_ranges
+        .iter()
+        .flat_map(|r| almanac.resolve_ranges(vec![r.clone()]))
+        .collect_vec();
+
+    let lowest = loc_ranges.iter().map(|r| r.start).min().unwrap();
+
+    Ok(lowest.into())
+}
+
+/// Resolves every seed in the seed ranges one by one. Only feasible for small
+/// inputs, but it does not depend on the range splitting of part2.
+pub fn part2_brute_force(input: &str) -> Solution {
+    let almanac = parse(input).map_err(Unsolved::Parse)?;
+
+    let lowest = almanac
+        .seed_ranges
+        .iter()
+        .flat_map(|r| r.clone())
+        .map(|seed| almanac.location(seed))
+        .min()
+        .unwrap();
+
+    Ok(lowest.into())
+}
+
+/// Generates an almanac with a few seed ranges and small maps. The sources of
+/// the entries of a map never overlap, like in the real inputs.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+
+    let max = 10 + 5 * size as u64;
+
+    let seeds = (0..1 + size.min(20) / 4)
+        .map(|_| format!("{} {}", rng.gen_range(0..max), rng.gen_range(1..=size as u64 + 1)))
+        .join(" ");
+    let mut out = format!("seeds: {seeds}\n");
+
+    for name in MAPS {
+        out.push_str(&format!("\n{name} map:\n"));
+
+        let mut entries = Vec::new();
+        let mut start = 0;
+        for _ in 0..rng.gen_range(1..=2 + size.min(20) / 3) {
+            start += rng.gen_range(0..5);
+            let count = rng.gen_range(1..=size as u64 + 2);
+            entries.push(format!("{} {start} {count}\n", rng.gen_range(0..max)));
+            start += count;
+        }
+
+        entries.shuffle(rng);
+        out.push_str(&entries.concat());
+    }
+
+    out
+}
+
+pub fn parse(input: &str) -> Result<Almanac> {
+    let parts = input
+        .split("\n\n")
+        .filter_map(|s| {
+            let trimmed = s.trim();
+            if trimmed.is_empty() {
+                None
+            } else {
+                Some(trimmed)
+            }
+        })
+        .collect_vec();
+
+    parse_input(parts)
+}
+
+fn parse_input(parts: Vec<&str>) -> Result<Almanac> {
+    let [seeds, seed_to_soil, soil_to_fertilizer, fertilizer_to_water, water_to_light, light_to_temp, temp_to_humidity, humidity_to_location] =
+        parts[..]
+    else {
+        return Err(format!("expected the seeds and 7 maps, got {} sections", parts.len()));
+    };
+
+    let seeds: Vec<u64> = seeds
+        .strip_prefix("seeds:")
+        .ok_or("expected the seeds first")?
+        .split_whitespace()
+        .map(|s| s.parse().map_err(|_| format!("invalid seed '{s}'")))
+        .collect::<Result<_>>()?;
+
+    if seeds.is_empty() || !seeds.len().is_multiple_of(2) {
+        return Err("expected pairs of seeds".to_string());
+    }
+
+    let mut seed_ranges = Vec::new();
+    for i in 0..seeds.len() / 2 {
+        let start = seeds[i * 2];
+        let end = start + seeds[i * 2 + 1];
+        seed_ranges.push(Range { start, end })
+    }
+
+    Ok(Almanac {
+        seeds,
+        seed_ranges,
+        seed_to_soil: parse_map(seed_to_soil)?,
+        soil_to_fertilizer: parse_map(soil_to_fertilizer)?,
+        fertilizer_to_water: parse_map(fertilizer_to_water)?,
+        water_to_light: parse_map(water_to_light)?,
+        light_to_temp: parse_map(light_to_temp)?,
+        temp_to_humidity: parse_map(temp_to_humidity)?,
+        humidity_to_location: parse_map(humidity_to_location)?,
+    })
+}
+
+fn parse_map(input: &str) -> Result<Map> {
+    let mut map = Map::new();
+
+    let mut lines = input.lines();
+    let header = lines.next().unwrap_or_default();
+    if !header.ends_with(" map:") {
+        return Err(format!("expected a map, got '{header}'"));
+    }
+
+    for line in lines {
+        let Some((dst_start, src_start, count)) = line
+            .split(" ")
+            .map(|s| s.parse::<u64>())
+            .collect_tuple()
+        else {
+            return Err(format!("expected three numbers, got '{line}'"));
+        };
+        let (Ok(dst_start), Ok(src_start), Ok(count)) = (dst_start, src_start, count) else {
+            return Err(format!("invalid number in '{line}'"));
+        };
+
+        map.entries.push(MapEntry {
+            src: Range {
+                start: src_start,
+                end: src_start + count,
+            },
+            dst: Range {
+                start: dst_start,
+                end: dst_start + count,
+            },
+        })
+    }
+
+    Ok(map)
+}
+
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub seed_ranges: Vec<Range<u64>>,
+    seed_to_soil: Map,
+    soil_to_fertilizer: Map,
+    fertilizer_to_water: Map,
+    water_to_light: Map,
+    light_to_temp: Map,
+    temp_to_humidity: Map,
+    humidity_to_location: Map,
+}
+
+impl Almanac {
+    pub fn location(&self, seed: u64) -> u64 {
+        self.maps().iter().fold(seed, |v, map| map.resolve(v))
+    }
+
+    /// Resolves ranges of seeds to the ranges of their locations, which are
+    /// sorted and do not overlap. Empty ranges are left out.
+    pub fn resolve_ranges(&self, seeds: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        let seeds = seeds.into_iter().filter(|r| !r.is_empty()).collect_vec();
+
+        self.maps()
+            .iter()
+            .fold(seeds, |ranges, map| map.resolve_ranges(ranges))
+    }
+
+    fn maps(&self) -> [&Map; 7] {
+        [
+            &self.seed_to_soil,
+            &self.soil_to_fertilizer,
+            &self.fertilizer_to_water,
+            &self.water_to_light,
+            &self.light_to_temp,
+            &self.temp_to_humidity,
+            &self.humidity_to_location,
+        ]
+    }
+}
+
+struct Map {
+    entries: Vec<MapEntry>,
+}
+
+impl Map {
+    pub fn new() -> Map {
+        Map {
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn resolve(&self, v: u64) -> u64 {
+        for e in self.entries.iter() {
+            if let Some(r) = e.map(v) {
+                return r;
+            }
+        }
+
+        return v;
+    }
+
+    pub fn resolve_ranges(&self, srcs: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        let mut to_resolve = srcs.into_iter().collect_vec();
+        to_resolve.sort_by(|a, b| a.start.cmp(&b.start));
+
+        let mut resolved = Vec::new();
+
+        while let Some(src) = to_resolve.pop() {
+            let mut found_match = false;
+            for e in self.entries.iter() {
+                if let Some((r, mut non_match)) = e.map_range(&src) {
+                    resolved.push(r);
+                    to_resolve.append(&mut non_match);
+                    found_match = true;
+                    break;
+                }
+            }
+            if !found_match {
+                resolved.push(src);
+            }
+        }
+
+        resolved.sort_by(|a, b| a.start.cmp(&b.start));
+
+        return combine_ranges(resolved);
+    }
+}
+
+#[derive(Debug)]
+struct MapEntry {
+    src: Range<u64>,
+    dst: Range<u64>,
+}
+
+impl MapEntry {
+    pub fn map_range(&self, r: &Range<u64>) -> Option<(Range<u64>, Vec<Range<u64>>)> {
+        if let Some(overlapping) = overlapping(&self.src, &r) {
+            let mut non_matching = Vec::new();
+
+            if r.start != overlapping.start {
+                non_matching.push(Range {
+                    start: r.start,
+                    end: overlapping.start,
+                });
+            }
+
+            if r.end != overlapping.end {
+                non_matching.push(Range {
+                    start: overlapping.end,
+                    end: r.end,
+                });
+            }
+
+            Some((
+                Range {
+                    start: self.map(overlapping.start).unwrap(),
+                    end: self.dst.start + (overlapping.end - self.src.start),
+                },
+                non_matching,
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn map(&self, v: u64) -> Option<u64> {
+        if self.src.contains(&v) {
+            let offset = v - self.src.start;
+            Some(self.dst.start + offset)
+        } else {
+            None
+        }
+    }
+}
+
+fn overlapping(first: &Range<u64>, second: &Range<u64>) -> Option<Range<u64>> {
+    if first.start >= second.end || first.end <= second.start {
+        None
+    } else {
+        Some(Range {
+            start: if first.start > second.start {
+                first.start
+            } else {
+                second.start
+            },
+            end: if first.end < second.end {
+                first.end
+            } else {
+                second.end
+            },
+        })
+    }
+}
+
+fn combine_ranges(rs: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    let mut result = Vec::new();
+
+    let mut curr: Option<Range<u64>> = None;
+    for r in rs {
+        if let Some(c) = curr {
+            if r.start <= c.end {
+                curr = Some(c.start..c.end.max(r.end));
+            } else {
+                result.push(c);
+                curr = Some(r);
+            }
+        } else {
+            curr = Some(r);
+        }
+    }
+
+    if let Some(c) = curr {
+        result.push(c);
+    }
+
+    return result;
+}
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
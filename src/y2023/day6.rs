@@ -0,0 +1,346 @@
+extern crate itertools;
+
+use std::ops::Range;
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    trace, util,
+};
+
+pub fn part1(input: &str) -> Solution {
+    let races = parse_races(input).map_err(Unsolved::Parse)?;
+
+    let score = races
+        .into_iter()
+        .map(|(time, record_distance)| {
+            let mut results = Vec::new();
+            for i in 1..time {
+                let distance = calc_distance(time, i);
+                if distance > record_distance {
+                    results.push(distance);
+                }
+            }
+
+            trace::emit!(
+                "race",
+                time = time,
+                record = record_distance,
+                ways_to_win = results.len(),
+            );
+
+            results.len()
+        })
+        .try_fold(1usize, |acc, v| acc.checked_mul(v))
+        .ok_or("there are too many ways to win to count")?;
+
+    Ok(score.into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input).collect_vec();
+    let (time, distance) = parse_race(&lines).map_err(Unsolved::Parse)?;
+
+    // The bisection needs a windup on each side of the best one.
+    if time < 3 {
+        return Err(format!("the race of {time} ms is too short").into());
+    }
+
+    let (left, right) = calc_breakpoints(time, distance);
+
+    Ok((right - left + 1).into())
+}
+
+/// Tries every windup of the single race.
+pub fn part2_brute_force(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input).collect_vec();
+    let [time, distance] = [("Time:", 0), ("Distance:", 1)].map(|(prefix, i)| {
+        lines
+            .get(i)
+            .and_then(|l| l.strip_prefix(prefix))
+            .and_then(|l| l.replace(" ", "").parse::<i64>().ok())
+    });
+    let (Some(time), Some(distance)) = (time, distance) else {
+        let err = "expected a line of times and a line of distances";
+        return Err(Unsolved::Parse(err.to_string()));
+    };
+
+    let ways = (0..=time)
+        .filter(|&w| calc_distance(time, w) > distance)
+        .count();
+
+    Ok(ways.into())
+}
+
+/// Generates one race that can be won, split into up to three races by
+/// splitting the digits of the time and the distance.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let time = rng.gen_range(7..=10 + 50 * size.min(2_000) as i64);
+    let distance = rng.gen_range(0..time * time / 4);
+
+    let races = rng.gen_range(1..=3);
+    let (times, distances) = (split_digits(rng, time, races), split_digits(rng, distance, races));
+
+    // Both need the same number of races, fall back to a single race.
+    let (times, distances) = if times.len() == distances.len() {
+        (times, distances)
+    } else {
+        (vec![time.to_string()], vec![distance.to_string()])
+    };
+
+    let widths = times
+        .iter()
+        .zip(distances.iter())
+        .map(|(t, d)| t.len().max(d.len()))
+        .collect_vec();
+    let row = |values: &[String]| {
+        values
+            .iter()
+            .zip(widths.iter())
+            .map(|(v, &w)| format!("{v:>w$}"))
+            .join("  ")
+    };
+
+    format!("Time:      {}\nDistance:  {}\n", row(&times), row(&distances))
+}
+
+/// Splits the digits of the value into at most `count` numbers, none of them
+/// starting with a zero.
+fn split_digits(rng: &mut StdRng, value: i64, count: usize) -> Vec<String> {
+    let digits = value.to_string();
+    let mut cuts = (1..digits.len())
+        .filter(|&i| !digits[i..].starts_with('0'))
+        .collect_vec();
+    cuts.shuffle(rng);
+    cuts.truncate(count - 1);
+    cuts.sort();
+
+    let mut start = 0;
+    let mut numbers = Vec::new();
+    for cut in cuts.into_iter().chain([digits.len()]) {
+        numbers.push(digits[start..cut].to_string());
+        start = cut;
+    }
+
+    numbers
+}
+
+/// Counts the ways to win from the roots of the quadratic below, instead of
+/// bisecting.
+pub fn part2_closed_form(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input).collect_vec();
+    let (time, distance) = parse_race(&lines).map_err(Unsolved::Parse)?;
+
+    let (left, right) = solve_breakpoints(time, distance);
+
+    Ok((right - left + 1).max(0).into())
+}
+
+/// Parses the races of part 1, as pairs of a time and a record distance.
+fn parse_races(input: &str) -> Result<Vec<(i64, i64)>> {
+    let (times, distances) = util::non_empty_lines(input)
+        .map(|s| parse_line(&s))
+        .collect_tuple()
+        .ok_or("expected a line of times and a line of distances")?;
+    let (times, distances) = (times?, distances?);
+    if times.len() != distances.len() {
+        return Err("expected as many times as distances".to_string());
+    }
+    for (&time, &distance) in times.iter().zip(distances.iter()) {
+        check_race(time, distance)?;
+    }
+
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+fn parse_line(s: &str) -> Result<Vec<i64>> {
+    s.split(" ")
+        .filter_map(|s| {
+            let trimmed = s.trim();
+            if trimmed.is_empty() {
+                None
+            } else {
+                Some(trimmed)
+            }
+        })
+        .skip(1)
+        .map(|n| n.parse().map_err(|_| format!("invalid number {n} in: {s}")))
+        .collect()
+}
+
+/// Parses the single race of part 2, where the spaces between digits are
+/// ignored.
+fn parse_race(lines: &[String]) -> Result<(i64, i64)> {
+    let parse = |i: usize, prefix: &str| {
+        lines
+            .get(i)
+            .and_then(|l| l.strip_prefix(prefix))
+            .ok_or(format!("expected a line starting with {prefix}"))?
+            .replace(" ", "")
+            .parse::<i64>()
+            .map_err(|err| format!("{prefix} {err}"))
+    };
+
+    let (time, distance) = (parse(0, "Time:")?, parse(1, "Distance:")?);
+    check_race(time, distance)?;
+
+    Ok((time, distance))
+}
+
+/// Real races are far shorter, the limits keep w * (t - w) and t^2 - 4 * D
+/// from overflowing.
+const MAX_TIME: i64 = 1 << 31;
+const MAX_DISTANCE: i64 = MAX_TIME * MAX_TIME / 4;
+
+fn check_race(time: i64, distance: i64) -> Result<()> {
+    if !(0..=MAX_TIME).contains(&time) {
+        return Err(format!("the time {time} is out of range"));
+    }
+    if !(0..=MAX_DISTANCE).contains(&distance) {
+        return Err(format!("the distance {distance} is out of range"));
+    }
+
+    Ok(())
+}
+
+fn calc_distance(time_limit: i64, windup: i64) -> i64 {
+    let speed = windup;
+    let time_left = time_limit - windup;
+
+    speed * time_left
+}
+
+#[test]
+fn test_calc_distance() {
+    assert_eq!(0, calc_distance(7, 0));
+    assert_eq!(6, calc_distance(7, 1));
+    assert_eq!(10, calc_distance(7, 2));
+    assert_eq!(12, calc_distance(7, 3));
+    assert_eq!(12, calc_distance(7, 4));
+    assert_eq!(10, calc_distance(7, 5));
+    assert_eq!(6, calc_distance(7, 6));
+    assert_eq!(0, calc_distance(7, 7));
+}
+
+// d = w * (t - w) = w*t - w^2
+// [d > D] => [w*t - w^2 > D] =>[ w*t - w^2 - D > 0]
+// w*t - w^2 - D = 0 => w^2 - w*t + D = 0 => w = t/2 +- sqrt(4*D - t^2)/2
+fn calc_breakpoints(time: i64, distance: i64) -> (i64, i64) {
+    let max = find_max(time, 1..time);
+    let left = bisect_left(time, distance, 1..max);
+    let right = bisect_right(time, distance, max..time);
+
+    return (left, right);
+}
+
+fn find_max(time: i64, r: Range<i64>) -> i64 {
+    if r.start == r.end - 1 {
+        return r.start;
+    }
+
+    let w = (r.start + r.end) / 2;
+    let d = calc_distance(time, w);
+    if w > 0 {
+        let before = calc_distance(time, w - 1);
+        if before > d {
+            return find_max(time, r.start..w);
+        }
+    }
+    if w < r.end - 1 {
+        let after = calc_distance(time, w + 1);
+        if after > d {
+            return find_max(time, w..r.end);
+        }
+    }
+
+    return w;
+}
+
+fn bisect_left(time: i64, distance: i64, r: Range<i64>) -> i64 {
+    if r.start == r.end - 1 {
+        let d = calc_distance(time, r.start);
+        if d > distance {
+            return r.start;
+        } else {
+            return r.start + 1;
+        }
+    }
+
+    let w = (r.start + r.end) / 2;
+    let d = w * (time - w);
+
+    if d > distance {
+        return bisect_left(time, distance, r.start..w);
+    } else {
+        return bisect_left(time, distance, w..r.end);
+    }
+}
+
+fn bisect_right(time: i64, distance: i64, r: Range<i64>) -> i64 {
+    if r.start == r.end - 1 {
+        let d = calc_distance(time, r.start);
+        if d > distance {
+            return r.start;
+        } else {
+            return r.start - 1;
+        }
+    }
+
+    let w = (r.start + r.end) / 2;
+    let d = w * (time - w);
+
+    if d > distance {
+        return bisect_right(time, distance, w..r.end);
+    } else {
+        return bisect_right(time, distance, r.start..w);
+    }
+}
+
+/// Finds the first and last windup that beat the distance from the roots of
+/// w^2 - w*t + D = 0. The roots are rounded inwards and then corrected, as the
+/// square root is not exact for large inputs.
+fn solve_breakpoints(time: i64, distance: i64) -> (i64, i64) {
+    let wins = |w: i64| calc_distance(time, w) > distance;
+
+    let discriminant = ((time * time - 4 * distance) as f64).max(0.0).sqrt();
+    let mut left = ((time as f64 - discriminant) / 2.0).floor() as i64 + 1;
+    let mut right = ((time as f64 + discriminant) / 2.0).ceil() as i64 - 1;
+
+    while left > 0 && wins(left - 1) {
+        left -= 1;
+    }
+    while left <= right && !wins(left) {
+        left += 1;
+    }
+    while right < time && wins(right + 1) {
+        right += 1;
+    }
+    while right >= left && !wins(right) {
+        right -= 1;
+    }
+
+    (left, right)
+}
+
+#[test]
+fn test_breakpoints() {
+    assert_eq!((2, 5), calc_breakpoints(7, 9));
+    assert_eq!((4, 11), calc_breakpoints(15, 40));
+    assert_eq!((11, 19), calc_breakpoints(30, 200));
+
+    for (time, distance) in [(7, 9), (15, 40), (30, 200), (71530, 940200)] {
+        assert_eq!(
+            calc_breakpoints(time, distance),
+            solve_breakpoints(time, distance)
+        );
+    }
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
@@ -1,49 +1,73 @@
 extern crate itertools;
 
-use std::{collections::HashMap, fmt::Display, hash::Hash, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    hash::Hash,
+    str::FromStr,
+};
 
-use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
 
-use crate::util;
+use crate::{
+    answer::{Solution, Unsolved},
+    trace, util,
+};
 
-pub fn part1(input: &str) -> Result<String> {
+pub fn part1(input: &str) -> Solution {
     let lines = util::non_empty_lines(input);
 
-    let mut hands = lines.map(|s| parse_hand(&s)).collect_vec();
+    let mut hands = lines
+        .map(|s| parse_hand(&s))
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
     hands.sort();
 
-    let score: usize = hands
+    let score: u64 = hands
         .iter()
         .enumerate()
-        .map(|(i, hand)| (i + 1) * hand.2)
+        .map(|(i, hand)| {
+            trace::emit!(
+                "hand",
+                cards = original_cards(&hand.1),
+                kind = hand.0.to_string(),
+                rank = i + 1,
+                bid = hand.2,
+                winnings = (i as u64 + 1) * hand.2,
+            );
+            (i as u64 + 1) * hand.2
+        })
         .sum();
 
-    Ok(format!("{}", score))
-}
-
-#[test]
-fn test_part1() {
-    assert_eq!("6440", part1(TEST_INPUT).unwrap());
+    Ok(score.into())
 }
 
-pub fn part2(input: &str) -> Result<String> {
+pub fn part2(input: &str) -> Solution {
     let lines = util::non_empty_lines(input);
 
-    let mut hands = lines.map(|s| parse_joker_hand(&s)).collect_vec();
+    let mut hands = lines
+        .map(|s| parse_joker_hand(&s))
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
     hands.sort();
 
-    let score: usize = hands
+    let score: u64 = hands
         .iter()
         .enumerate()
-        .map(|(i, hand)| (i + 1) * hand.2)
+        .map(|(i, hand)| {
+            trace::emit!(
+                "hand",
+                cards = original_cards(&hand.1),
+                kind = hand.0.to_string(),
+                rank = i + 1,
+                bid = hand.2,
+                winnings = (i as u64 + 1) * hand.2,
+            );
+            (i as u64 + 1) * hand.2
+        })
         .sum();
 
-    Ok(format!("{}", score))
-}
-
-#[test]
-fn test_part2() {
-    assert_eq!("5905", part2(TEST_INPUT).unwrap());
+    Ok(score.into())
 }
 
 struct Hand(Kind, String, u32);
@@ -56,8 +80,8 @@ impl Display for Hand {
     }
 }
 
-fn parse_hand(s: &str) -> (Kind, String, usize) {
-    let (cards_str, bid) = s.split_once(" ").unwrap();
+fn parse_hand(s: &str) -> Result<(Kind, String, u64)> {
+    let (cards_str, bid) = split_hand(s)?;
 
     let cards = cards_str
         .chars()
@@ -71,11 +95,11 @@ fn parse_hand(s: &str) -> (Kind, String, usize) {
         })
         .collect::<String>();
 
-    (cards.parse().unwrap(), cards, bid.parse().unwrap())
+    Ok((cards.parse()?, cards, bid))
 }
 
-fn parse_joker_hand(s: &str) -> (Kind, String, usize) {
-    let (cards_str, bid) = s.split_once(" ").unwrap();
+fn parse_joker_hand(s: &str) -> Result<(Kind, String, u64)> {
+    let (cards_str, bid) = split_hand(s)?;
 
     let cards = cards_str
         .chars()
@@ -89,11 +113,40 @@ fn parse_joker_hand(s: &str) -> (Kind, String, usize) {
         })
         .collect::<String>();
 
-    (
-        replace_joker(cards.clone()).parse().unwrap(),
+    Ok((
+        replace_joker(cards.clone()).parse()?,
         cards.replace('B', "0"), // The joker is the weakest card
-        bid.parse().unwrap(),
-    )
+        bid,
+    ))
+}
+
+/// Splits a line into its five cards and its bid.
+fn split_hand(s: &str) -> Result<(&str, u64)> {
+    let invalid = || format!("invalid hand: {s}");
+
+    let (cards, bid) = s.split_once(" ").ok_or_else(invalid)?;
+    if cards.len() != 5 || !cards.chars().all(|c| "23456789TJQKA".contains(c)) {
+        return Err(invalid());
+    }
+    let bid = bid.parse::<u32>().map_err(|_| invalid())?;
+
+    Ok((cards, bid as u64))
+}
+
+/// Reverts the card relabeling done when parsing, so the hand can be shown the
+/// way it was written in the input.
+fn original_cards(cards: &str) -> String {
+    cards
+        .chars()
+        .map(|c| match c {
+            'E' => 'A',
+            'D' => 'K',
+            'C' => 'Q',
+            'B' | '0' => 'J',
+            'A' => 'T',
+            _ => c,
+        })
+        .collect()
 }
 
 fn replace_joker(hand: String) -> String {
@@ -175,18 +228,29 @@ fn count_items<T: Copy + Hash + Eq + PartialEq>(v: &Vec<T>) -> HashMap<T, usize>
     counts
 }
 
+/// Generates distinct hands of five cards with bids.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    const CARDS: &[u8] = b"23456789TJQKA";
+
+    let mut hands = HashSet::new();
+    let mut out = String::new();
+
+    for _ in 0..1 + size.min(1_000) {
+        let cards = (0..5)
+            .map(|_| *CARDS.choose(rng).unwrap() as char)
+            .collect::<String>();
+
+        if hands.insert(cards.clone()) {
+            out.push_str(&format!("{cards} {}\n", rng.gen_range(1..=1_000)));
+        }
+    }
+
+    out
+}
+
 // -------------------------------------
 
 type Error = String;
 type Result<T> = std::result::Result<T, Error>;
 
 // -------------------------------------
-
-#[cfg(test)]
-const TEST_INPUT: &str = "
-32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483
-";
@@ -0,0 +1,201 @@
+extern crate itertools;
+extern crate num;
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    util,
+};
+
+pub fn part1(input: &str) -> Solution {
+    let (instructions, network) = parse_input(input).map_err(Unsolved::Parse)?;
+
+    if !network.nodes.contains_key("AAA") {
+        return Err("there is no node AAA".into());
+    }
+
+    let mut curr = "AAA";
+
+    // Past this many steps a node has been seen twice at the same point of the
+    // instructions, so the walk is going round in circles.
+    for step in 0..=network.nodes.len() * instructions.len() {
+        if curr == "ZZZ" {
+            return Ok(step.into());
+        }
+        match instructions[step % instructions.len()] {
+            'L' => {
+                curr = &network.nodes[curr].0;
+            }
+            'R' => {
+                curr = &network.nodes[curr].1;
+            }
+            _ => {}
+        }
+    }
+
+    Err("ZZZ is never reached from AAA".into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let (instructions, network) = parse_input(input).map_err(Unsolved::Parse)?;
+
+    let curr = network
+        .nodes
+        .keys()
+        .filter(|k| k.ends_with("A"))
+        .collect_vec();
+
+    let mut moves = Vec::new();
+    for i in 0..curr.len() {
+        moves.push(network.get_moves(&instructions, curr[i])?);
+    }
+
+    let mut lcm = moves.pop().ok_or("there are no nodes ending in A")?;
+    while let Some(m) = moves.pop() {
+        lcm = (lcm / num::integer::gcd(lcm, m))
+            .checked_mul(m)
+            .ok_or("the number of steps is too large")?;
+    }
+
+    return Ok(lcm.into());
+}
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Network)> {
+    let parts = input
+        .split_once("\n\n")
+        .ok_or("expected the instructions and the network")?;
+
+    let instructions = parts.0.trim().chars().collect_vec();
+    if instructions.is_empty() || instructions.iter().any(|&c| c != 'L' && c != 'R') {
+        return Err(format!("invalid instructions: {}", parts.0.trim()));
+    }
+
+    Ok((instructions, parts.1.trim().parse()?))
+}
+
+struct Network {
+    nodes: HashMap<String, (String, String)>,
+}
+
+impl Network {
+    fn get_moves<'a>(&'a self, instructions: &Vec<char>, start: &'a String) -> Result<usize> {
+        let mut curr = start;
+
+        for step in 0..=self.nodes.len() * instructions.len() {
+            if curr.ends_with("Z") {
+                return Ok(step);
+            }
+
+            match instructions[step % instructions.len()] {
+                'L' => curr = &self.nodes[curr].0,
+                'R' => curr = &self.nodes[curr].1,
+                _ => {}
+            }
+        }
+
+        Err(format!("no node ending in Z is reached from {start}"))
+    }
+}
+
+impl FromStr for Network {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        let mut network = HashMap::new();
+
+        let lines = util::non_empty_lines(s);
+        for l in lines {
+            let invalid = || format!("invalid node: {l}");
+
+            let (name, dsts) = l.split_once(" = ").ok_or_else(invalid)?;
+
+            let (left, right) = dsts
+                .strip_prefix("(")
+                .and_then(|d| d.strip_suffix(")"))
+                .and_then(|d| d.split_once(", "))
+                .ok_or_else(invalid)?;
+
+            network.insert(name.to_owned(), (left.to_owned(), right.to_owned()));
+        }
+
+        // Every node leads to a node of the network, so walking it never
+        // looks up a missing node.
+        for dst in network.values().flat_map(|(left, right)| [left, right]) {
+            if !network.contains_key(dst) {
+                return Err(format!("there is no node {dst}"));
+            }
+        }
+
+        Ok(Network { nodes: network })
+    }
+}
+
+/// Generates a network with the shape of the inputs: the instructions have a
+/// prime length, and each ghost walks a loop from its A node to its Z node and
+/// back whose length is a different prime times the length of the
+/// instructions. Both directions of a node lead to the same node, so the
+/// instructions never matter.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    const PRIMES: [usize; 10] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
+
+    let mut primes = PRIMES[..3 + size.min(70) / 10].to_vec();
+    primes.shuffle(rng);
+    let length = primes.pop().unwrap();
+    let ghosts = rng.gen_range(2..=primes.len().min(5));
+
+    let instructions = (0..length)
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect::<String>();
+
+    let mut names = HashSet::new();
+    let mut name = |rng: &mut StdRng, last: u8| loop {
+        let name = format!(
+            "{}{}{}",
+            rng.gen_range(b'A'..=b'Z') as char,
+            rng.gen_range(b'A'..=b'Z') as char,
+            last as char
+        );
+        if name != "AAA" && name != "ZZZ" && names.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    let mut lines = Vec::new();
+    for (i, &prime) in primes[..ghosts].iter().enumerate() {
+        let (start, end) = if i == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            (name(rng, b'A'), name(rng, b'Z'))
+        };
+
+        let mut path = vec![start];
+        for _ in 1..prime * length {
+            let last = rng.gen_range(b'B'..=b'Y');
+            path.push(name(rng, last));
+        }
+        path.push(end);
+
+        for (j, node) in path.iter().enumerate() {
+            // The Z node continues like the A node.
+            let next = &path[if j + 1 < path.len() { j + 1 } else { 1 }];
+            lines.push(format!("{node} = ({next}, {next})\n"));
+        }
+    }
+    lines.shuffle(rng);
+
+    format!("{instructions}\n\n{}", lines.concat())
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------
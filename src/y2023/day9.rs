@@ -0,0 +1,146 @@
+extern crate itertools;
+extern crate num;
+
+use std::{fmt::Display, num::ParseIntError, str::FromStr};
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    answer::{Solution, Unsolved},
+    trace, util,
+};
+
+pub fn part1(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input);
+
+    let history = lines
+        .map(|s| s.parse::<History>())
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
+
+    let extrapolations = history
+        .iter()
+        .map(|h| h.extrapolate())
+        .try_fold(0i32, |acc, v| acc.checked_add(v?).ok_or_else(too_large))?;
+
+    Ok(extrapolations.into())
+}
+
+pub fn part2(input: &str) -> Solution {
+    let lines = util::non_empty_lines(input);
+
+    let history = lines
+        .map(|s| s.parse::<History>())
+        .collect::<Result<Vec<_>>>()
+        .map_err(Unsolved::Parse)?;
+
+    let extrapolations = history
+        .iter()
+        .map(|h| h.extrapolate_history())
+        .try_fold(0i32, |acc, v| acc.checked_add(v?).ok_or_else(too_large))?;
+
+    Ok(extrapolations.into())
+}
+
+struct History {
+    values: Vec<i32>,
+}
+
+impl History {
+    fn extrapolate(&self) -> Result<i32> {
+        let next = do_it(&self.values).ok_or_else(too_large)?;
+        trace::emit!("history", values = self.to_string(), next = next);
+
+        Ok(next)
+    }
+    fn extrapolate_history(&self) -> Result<i32> {
+        let previous = do_it_backwards(&self.values).ok_or_else(too_large)?;
+        trace::emit!("history", values = self.to_string(), previous = previous);
+
+        Ok(previous)
+    }
+}
+
+fn too_large() -> Error {
+    "the extrapolated values are too large".to_string()
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.values.iter().join(" "))
+    }
+}
+
+fn do_it(vec: &Vec<i32>) -> Option<i32> {
+    if vec.iter().all(|&v| v == 0) {
+        return Some(0);
+    }
+
+    let mut next_level = Vec::new();
+    for i in 0..vec.len() - 1 {
+        let left = vec[i];
+        let right = vec[i + 1];
+
+        next_level.push(right.checked_sub(left)?);
+    }
+
+    let incr = do_it(&next_level)?;
+
+    vec[vec.len() - 1].checked_add(incr)
+}
+
+fn do_it_backwards(vec: &Vec<i32>) -> Option<i32> {
+    if vec.iter().all(|&v| v == 0) {
+        return Some(0);
+    }
+
+    let mut next_level = Vec::new();
+    for i in 0..vec.len() - 1 {
+        let left = vec[i];
+        let right = vec[i + 1];
+
+        next_level.push(right.checked_sub(left)?);
+    }
+
+    let incr = do_it_backwards(&next_level)?;
+
+    vec[0].checked_sub(incr)
+}
+
+impl FromStr for History {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let values: Result<Vec<_>> = s
+            .trim()
+            .split(" ")
+            .map(|s| s.parse().map_err(|err: ParseIntError| err.to_string()))
+            .collect();
+
+        Ok(History { values: values? })
+    }
+}
+
+/// Generates histories of 21 values of polynomials with small coefficients.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..1 + size.min(1_000))
+        .map(|_| {
+            let coefficients = (0..rng.gen_range(1..=5))
+                .map(|_| rng.gen_range(-10..=10))
+                .collect_vec();
+
+            let values =
+                (0..21i64).map(|x| coefficients.iter().rev().fold(0, |acc, c| acc * x + c));
+
+            values.map(|v| v.to_string()).join(" ") + "\n"
+        })
+        .collect()
+}
+
+// -------------------------------------
+
+type Error = String;
+type Result<T> = std::result::Result<T, Error>;
+
+// -------------------------------------